@@ -0,0 +1,438 @@
+//! Rust source generation from a parsed [`Dictionary`].
+use crate::dictionary::{Dictionary, FieldType, Message};
+use std::fmt;
+use std::fmt::Write;
+
+/// Errors that can occur while generating Rust source from a [`Dictionary`].
+#[derive(Debug)]
+pub enum CodegenError {
+    /// A `DATA`-typed field has no resolvable length tag (see [`crate::dictionary::Field::length_tag`]),
+    /// so codegen has no length tag to pair it with for `DataTags` parsing. Rather than generate a
+    /// `parse()` that silently truncates the field's value at the first embedded `SOH` byte, this
+    /// message is rejected until the dictionary declares a `<Name>Len`/`<Name>Length` field for it.
+    UnresolvedDataLength {
+        /// The message referencing the field.
+        message: String,
+        /// The `DATA` field with no resolvable length tag.
+        field: String,
+    },
+}
+
+impl fmt::Display for CodegenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CodegenError::UnresolvedDataLength { message, field } => write!(
+                f,
+                "message `{}` field `{}` is DATA-typed but has no resolvable length tag",
+                message, field
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CodegenError {}
+
+/// Generate a single Rust source file defining one struct per dictionary message, with typed
+/// fields and a `parse`/`to_bytes` pair built on `fixity_core::wire_format`.
+///
+/// Fields are generated in declaration order; fields the dictionary doesn't mark `required="Y"`
+/// are wrapped in `Option<T>`. The generated code expects to be placed in a module that has
+/// `fixity_core` available as an extern crate.
+pub fn generate(dictionary: &Dictionary) -> Result<String, CodegenError> {
+    let mut out = String::new();
+    writeln!(out, "// @generated by fixity-codegen. Do not edit by hand.").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "{}", WRITE_FIELD_HELPER).unwrap();
+
+    for message in &dictionary.messages {
+        generate_message(&mut out, dictionary, message)?;
+    }
+
+    Ok(out)
+}
+
+const WRITE_FIELD_HELPER: &str = "\
+fn write_field<'a, T, E>(buf: &mut [u8], prefix: &'static [u8], value: &T) -> Option<usize>
+where
+    T: fixity_core::data_types::FixValue<'a, E>,
+{
+    if buf.len() < prefix.len() {
+        return None;
+    }
+    buf[..prefix.len()].copy_from_slice(prefix);
+    let mut index = prefix.len();
+
+    index += value.to_bytes(&mut buf[index..])?;
+
+    if index >= buf.len() {
+        return None;
+    }
+    buf[index] = fixity_core::SOH;
+    Some(index + 1)
+}
+";
+
+fn generate_message(
+    out: &mut String,
+    dictionary: &Dictionary,
+    message: &Message,
+) -> Result<(), CodegenError> {
+    let struct_name = &message.name;
+
+    // (length_tag, data_tag) pairs for this message's DATA fields, for DataTagTable below.
+    let mut data_pairs: Vec<(u16, u16)> = Vec::new();
+    for field_ref in &message.fields {
+        let field = match dictionary.fields.get(&field_ref.name) {
+            Some(f) => f,
+            None => continue,
+        };
+        if field.field_type != FieldType::Data {
+            continue;
+        }
+        let length_tag = field
+            .length_tag
+            .ok_or_else(|| CodegenError::UnresolvedDataLength {
+                message: message.name.clone(),
+                field: field.name.clone(),
+            })?;
+        data_pairs.push((length_tag, field.number));
+    }
+
+    writeln!(
+        out,
+        "/// Generated from the `{}` message definition (`MsgType` `{}`).",
+        message.name, message.msg_type
+    )
+    .unwrap();
+    writeln!(out, "#[derive(Debug, PartialEq)]").unwrap();
+    writeln!(out, "pub struct {}<'a> {{", struct_name).unwrap();
+    for field_ref in &message.fields {
+        let field = match dictionary.fields.get(&field_ref.name) {
+            Some(f) => f,
+            None => continue,
+        };
+        let ty = if field_ref.required {
+            field.field_type.rust_type().to_string()
+        } else {
+            format!("Option<{}>", field.field_type.rust_type())
+        };
+        writeln!(out, "    /// Tag {} (`{}`).", field.number, field.name).unwrap();
+        writeln!(out, "    pub {}: {},", field_name(&field.name), ty).unwrap();
+    }
+    writeln!(
+        out,
+        "    _marker: core::marker::PhantomData<&'a ()>,"
+    )
+    .unwrap();
+    writeln!(out, "}}").unwrap();
+    writeln!(out).unwrap();
+
+    writeln!(
+        out,
+        "/// Errors that can occur while parsing a `{}` message body.",
+        struct_name
+    )
+    .unwrap();
+    writeln!(out, "#[derive(Debug, PartialEq)]").unwrap();
+    writeln!(out, "pub enum {}ParseError {{", struct_name).unwrap();
+    writeln!(
+        out,
+        "    /// A required field was not present in the message body."
+    )
+    .unwrap();
+    writeln!(out, "    MissingField(&'static str),").unwrap();
+    writeln!(
+        out,
+        "    /// The value of the named tag could not be parsed into its native type."
+    )
+    .unwrap();
+    writeln!(out, "    Field {{ tag: u16 }},").unwrap();
+    if !data_pairs.is_empty() {
+        writeln!(
+            out,
+            "    /// A length-prefixed data tag could not be resolved."
+        )
+        .unwrap();
+        writeln!(out, "    DataTag(fixity_core::wire_format::DataTagError),").unwrap();
+    }
+    writeln!(out, "}}").unwrap();
+    writeln!(out).unwrap();
+
+    writeln!(out, "impl<'a> {}<'a> {{", struct_name).unwrap();
+    writeln!(
+        out,
+        "    /// Parse a `{}` message body (the tags between `BodyLength` and `CheckSum`).",
+        struct_name
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "    pub fn parse(body: &'a [u8]) -> Result<Self, {}ParseError> {{",
+        struct_name
+    )
+    .unwrap();
+    for field_ref in &message.fields {
+        if dictionary.fields.contains_key(&field_ref.name) {
+            writeln!(out, "        let mut {} = None;", field_name(&field_ref.name)).unwrap();
+        }
+    }
+    if data_pairs.is_empty() {
+        writeln!(
+            out,
+            "        for raw in fixity_core::wire_format::Tags::new(body) {{"
+        )
+        .unwrap();
+        writeln!(out, "            match raw.tag {{").unwrap();
+    } else {
+        writeln!(
+            out,
+            "        let mut data_tags = fixity_core::wire_format::DataTagTable::empty();"
+        )
+        .unwrap();
+        for (length_tag, data_tag) in &data_pairs {
+            writeln!(out, "        data_tags.register({}, {});", length_tag, data_tag).unwrap();
+        }
+        writeln!(
+            out,
+            "        for raw in fixity_core::wire_format::DataTags::new(body, data_tags) {{"
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "            let raw = raw.map_err({}ParseError::DataTag)?;",
+            struct_name
+        )
+        .unwrap();
+        writeln!(out, "            match raw.tag {{").unwrap();
+    }
+    for field_ref in &message.fields {
+        let field = match dictionary.fields.get(&field_ref.name) {
+            Some(f) => f,
+            None => continue,
+        };
+        writeln!(
+            out,
+            "                {} => {} = Some(<{} as fixity_core::data_types::FixValue<'_, _>>::from_bytes(raw.value).map_err(|_| {}ParseError::Field {{ tag: raw.tag }})?),",
+            field.number,
+            field_name(&field.name),
+            field.field_type.call_type(),
+            struct_name
+        )
+        .unwrap();
+    }
+    writeln!(out, "                _ => {{}}").unwrap();
+    writeln!(out, "            }}").unwrap();
+    writeln!(out, "        }}").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "        Ok({} {{", struct_name).unwrap();
+    for field_ref in &message.fields {
+        if !dictionary.fields.contains_key(&field_ref.name) {
+            continue;
+        }
+        let var = field_name(&field_ref.name);
+        if field_ref.required {
+            writeln!(
+                out,
+                "            {}: {}.ok_or({}ParseError::MissingField(\"{}\"))?,",
+                var, var, struct_name, field_ref.name
+            )
+            .unwrap();
+        } else {
+            writeln!(out, "            {},", var).unwrap();
+        }
+    }
+    writeln!(out, "            _marker: core::marker::PhantomData,").unwrap();
+    writeln!(out, "        }})").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out).unwrap();
+
+    writeln!(
+        out,
+        "    /// Serialize this message's fields into `buf`, returning the number of bytes"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "    /// written, or `None` if `buf` isn't large enough."
+    )
+    .unwrap();
+    writeln!(out, "    pub fn to_bytes(&self, buf: &mut [u8]) -> Option<usize> {{").unwrap();
+    writeln!(out, "        let mut index = 0;").unwrap();
+    for field_ref in &message.fields {
+        let field = match dictionary.fields.get(&field_ref.name) {
+            Some(f) => f,
+            None => continue,
+        };
+        let prefix = format!("b\"{}=\"", field.number);
+        let var = format!("self.{}", field_name(&field.name));
+        if field_ref.required {
+            writeln!(
+                out,
+                "        index += write_field(&mut buf[index..], {}, &{})?;",
+                prefix, var
+            )
+            .unwrap();
+        } else {
+            writeln!(out, "        if let Some(ref value) = {} {{", var).unwrap();
+            writeln!(
+                out,
+                "            index += write_field(&mut buf[index..], {}, value)?;",
+                prefix
+            )
+            .unwrap();
+            writeln!(out, "        }}").unwrap();
+        }
+    }
+    writeln!(out, "        Some(index)").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}").unwrap();
+    writeln!(out).unwrap();
+
+    Ok(())
+}
+
+/// Convert a QuickFIX `PascalCase` field name (e.g. `OrderQty`) into the `snake_case` identifier
+/// it's generated as (e.g. `order_qty`).
+///
+/// A run of consecutive uppercase letters is treated as one segment (an acronym like `ID` or
+/// `CFI`), not split letter-by-letter, so `ClOrdID` becomes `cl_ord_id` rather than
+/// `cl_ord_i_d`. A new segment starts at an uppercase letter that follows a lowercase letter
+/// (`OrderQty` -> `order_qty`), or at the last letter of an uppercase run when it's followed by a
+/// lowercase letter (`CFICode` -> `cfi_code`).
+fn field_name(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut out = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if i != 0 && c.is_uppercase() {
+            let prev = chars[i - 1];
+            let next = chars.get(i + 1);
+            let starts_new_segment = prev.is_lowercase()
+                || (prev.is_uppercase() && next.map_or(false, |n| n.is_lowercase()));
+            if starts_new_segment {
+                out.push('_');
+            }
+        }
+        out.push(c.to_ascii_lowercase());
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate, CodegenError};
+    use crate::dictionary::Dictionary;
+
+    const SIMPLE: &str = r#"
+        <fix>
+            <fields>
+                <field number="38" name="OrderQty" type="QTY"/>
+                <field number="44" name="Price" type="PRICE"/>
+            </fields>
+            <messages>
+                <message name="NewOrderSingle" msgtype="D">
+                    <field name="OrderQty" required="Y"/>
+                    <field name="Price" required="N"/>
+                </message>
+            </messages>
+        </fix>
+    "#;
+
+    #[test]
+    fn generates_struct_and_methods() {
+        let dict = Dictionary::parse(SIMPLE).unwrap();
+        let source = generate(&dict).unwrap();
+
+        assert!(source.contains("pub struct NewOrderSingle<'a> {"));
+        assert!(source.contains("pub order_qty: fixity_core::data_types::decimal::Decimal,"));
+        assert!(source.contains("pub price: Option<fixity_core::data_types::decimal::Decimal>,"));
+        assert!(source.contains("pub fn parse(body: &'a [u8]) -> Result<Self, NewOrderSingleParseError> {"));
+        assert!(source.contains("pub fn to_bytes(&self, buf: &mut [u8]) -> Option<usize> {"));
+    }
+
+    #[test]
+    fn generates_char_field_as_char_newtype() {
+        let xml = r#"
+            <fix>
+                <fields>
+                    <field number="43" name="PossDupFlag" type="BOOLEAN"/>
+                </fields>
+                <messages>
+                    <message name="Heartbeat" msgtype="0">
+                        <field name="PossDupFlag" required="N"/>
+                    </message>
+                </messages>
+            </fix>
+        "#;
+        let dict = Dictionary::parse(xml).unwrap();
+        let source = generate(&dict).unwrap();
+
+        assert!(source.contains(
+            "pub poss_dup_flag: Option<fixity_core::data_types::char_value::Char>,"
+        ));
+        assert!(source.contains(
+            "<fixity_core::data_types::char_value::Char as fixity_core::data_types::FixValue<'_, _>>::from_bytes"
+        ));
+    }
+
+    #[test]
+    fn generates_data_tag_aware_parse() {
+        let xml = r#"
+            <fix>
+                <fields>
+                    <field number="95" name="RawDataLength" type="LENGTH"/>
+                    <field number="96" name="RawData" type="DATA"/>
+                </fields>
+                <messages>
+                    <message name="Logon" msgtype="A">
+                        <field name="RawDataLength" required="N"/>
+                        <field name="RawData" required="N"/>
+                    </message>
+                </messages>
+            </fix>
+        "#;
+        let dict = Dictionary::parse(xml).unwrap();
+        let source = generate(&dict).unwrap();
+
+        assert!(source.contains("let mut data_tags = fixity_core::wire_format::DataTagTable::empty();"));
+        assert!(source.contains("data_tags.register(95, 96);"));
+        assert!(source.contains("fixity_core::wire_format::DataTags::new(body, data_tags)"));
+        assert!(source.contains("DataTag(fixity_core::wire_format::DataTagError),"));
+    }
+
+    #[test]
+    fn rejects_data_field_with_unresolved_length_tag() {
+        let xml = r#"
+            <fix>
+                <fields>
+                    <field number="96" name="RawData" type="DATA"/>
+                </fields>
+                <messages>
+                    <message name="Logon" msgtype="A">
+                        <field name="RawData" required="N"/>
+                    </message>
+                </messages>
+            </fix>
+        "#;
+        let dict = Dictionary::parse(xml).unwrap();
+
+        assert!(matches!(
+            generate(&dict),
+            Err(CodegenError::UnresolvedDataLength { .. })
+        ));
+    }
+
+    #[test]
+    fn field_name_conversion() {
+        assert_eq!(super::field_name("OrderQty"), "order_qty");
+        assert_eq!(super::field_name("MsgType"), "msg_type");
+        assert_eq!(super::field_name("Price"), "price");
+    }
+
+    #[test]
+    fn field_name_conversion_acronym_runs() {
+        assert_eq!(super::field_name("ClOrdID"), "cl_ord_id");
+        assert_eq!(super::field_name("OrderID"), "order_id");
+        assert_eq!(super::field_name("ExecID"), "exec_id");
+        assert_eq!(super::field_name("CFICode"), "cfi_code");
+    }
+}
@@ -0,0 +1,341 @@
+//! Parsing of QuickFIX-style XML data dictionaries into an in-memory [`Dictionary`].
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// The native Rust type a FIX field's wire-format value maps onto for codegen purposes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldType {
+    /// `INT`, `LENGTH`, `SEQNUM`, `NUMINGROUP`, `DAYOFMONTH` and similar unsigned-integer types.
+    Int,
+    /// `PRICE`, `QTY`, `AMT`, `FLOAT`, `PRICEOFFSET` and similar exact-precision decimals.
+    Decimal,
+    /// `CHAR` and `BOOLEAN` - a single ASCII byte.
+    Char,
+    /// `DATA` - a raw, unvalidated byte slice whose length is carried by a preceding tag.
+    Data,
+    /// Every other type (`STRING`, `UTCTIMESTAMP`, `CURRENCY`, ...), which `fixity-core` has no
+    /// dedicated native representation for yet and so passes through as UTF-8 text.
+    Str,
+}
+
+impl FieldType {
+    /// Map a QuickFIX dictionary `type` attribute onto the [`FieldType`] `fixity-core` can
+    /// represent today.
+    pub fn from_quickfix(name: &str) -> FieldType {
+        match name {
+            "INT" | "LENGTH" | "SEQNUM" | "NUMINGROUP" | "DAYOFMONTH" => FieldType::Int,
+            "PRICE" | "QTY" | "AMT" | "FLOAT" | "PRICEOFFSET" => FieldType::Decimal,
+            "CHAR" | "BOOLEAN" => FieldType::Char,
+            "DATA" => FieldType::Data,
+            _ => FieldType::Str,
+        }
+    }
+
+    /// The `fixity_core`-rooted Rust type a field of this type is generated as, for use in a
+    /// struct field position (reference types carry the struct's `'a` lifetime).
+    pub fn rust_type(self) -> &'static str {
+        match self {
+            FieldType::Int => "u32",
+            FieldType::Decimal => "fixity_core::data_types::decimal::Decimal",
+            FieldType::Char => "fixity_core::data_types::char_value::Char",
+            FieldType::Data => "&'a [u8]",
+            FieldType::Str => "&'a str",
+        }
+    }
+
+    /// The same type as [`FieldType::rust_type`], but with reference lifetimes elided for use in
+    /// a `<Type as FixValue<..>>::from_bytes(..)` qualified call.
+    pub fn call_type(self) -> &'static str {
+        match self {
+            FieldType::Int => "u32",
+            FieldType::Decimal => "fixity_core::data_types::decimal::Decimal",
+            FieldType::Char => "fixity_core::data_types::char_value::Char",
+            FieldType::Data => "&[u8]",
+            FieldType::Str => "&str",
+        }
+    }
+}
+
+/// A single `<field>` definition: its tag number, name, and wire type.
+#[derive(Debug, Clone)]
+pub struct Field {
+    /// The FIX tag number this field is carried under.
+    pub number: u16,
+    /// The dictionary's name for this field, e.g. `OrderQty`.
+    pub name: String,
+    /// The native type this field's value is generated as.
+    pub field_type: FieldType,
+    /// For a [`FieldType::Data`] field, the tag number of the field carrying its length (e.g.
+    /// `RawData`'s `length_tag` is `RawDataLength`'s tag number), resolved from the dictionary's
+    /// `<Name>Len`/`<Name>Length` naming convention. `None` for every other field type, or if no
+    /// matching length field was declared.
+    pub length_tag: Option<u16>,
+}
+
+/// A field reference within a `<message>`, naming a [`Field`] and whether the message requires it.
+#[derive(Debug, Clone)]
+pub struct FieldRef {
+    /// Name of the referenced [`Field`].
+    pub name: String,
+    /// Whether the dictionary marks this field required (`required="Y"`).
+    pub required: bool,
+}
+
+/// A single `<message>` definition.
+#[derive(Debug, Clone)]
+pub struct Message {
+    /// The dictionary's name for this message, e.g. `NewOrderSingle`.
+    pub name: String,
+    /// The FIX `MsgType` (tag 35) value identifying this message, e.g. `D`.
+    pub msg_type: String,
+    /// The message's top-level fields, in declaration order. Fields nested inside a `<group>` or
+    /// `<component>` are not included - see the [crate docs](crate).
+    pub fields: Vec<FieldRef>,
+}
+
+/// A parsed QuickFIX data dictionary: every `<field>` and `<message>` definition.
+#[derive(Debug, Clone, Default)]
+pub struct Dictionary {
+    /// Every `<field>` definition, keyed by its dictionary name.
+    pub fields: BTreeMap<String, Field>,
+    /// Every `<message>` definition, in document order.
+    pub messages: Vec<Message>,
+}
+
+/// Errors that can occur while parsing a data dictionary.
+#[derive(Debug)]
+pub enum DictionaryError {
+    /// The dictionary was not well-formed XML.
+    Xml(roxmltree::Error),
+    /// An element was missing an attribute required to interpret it.
+    MissingAttribute {
+        /// The element that was missing the attribute, e.g. `"field"`.
+        element: &'static str,
+        /// The missing attribute's name, e.g. `"number"`.
+        attribute: &'static str,
+    },
+    /// A `<message>` referenced a field name that no `<field>` definition declared.
+    UnknownField(String),
+}
+
+impl fmt::Display for DictionaryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DictionaryError::Xml(e) => write!(f, "malformed dictionary XML: {}", e),
+            DictionaryError::MissingAttribute { element, attribute } => {
+                write!(f, "<{}> is missing required attribute `{}`", element, attribute)
+            }
+            DictionaryError::UnknownField(name) => {
+                write!(f, "message references undefined field `{}`", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DictionaryError {}
+
+impl Dictionary {
+    /// Parse a QuickFIX-style XML data dictionary.
+    pub fn parse(xml: &str) -> Result<Dictionary, DictionaryError> {
+        let doc = roxmltree::Document::parse(xml).map_err(DictionaryError::Xml)?;
+        let root = doc.root_element();
+
+        let mut fields = BTreeMap::new();
+        for fields_el in root.children().filter(|n| n.has_tag_name("fields")) {
+            for field_el in fields_el.children().filter(|n| n.has_tag_name("field")) {
+                let name = field_el
+                    .attribute("name")
+                    .ok_or(DictionaryError::MissingAttribute {
+                        element: "field",
+                        attribute: "name",
+                    })?
+                    .to_string();
+                let number: u16 = field_el
+                    .attribute("number")
+                    .ok_or(DictionaryError::MissingAttribute {
+                        element: "field",
+                        attribute: "number",
+                    })?
+                    .parse()
+                    .map_err(|_| DictionaryError::MissingAttribute {
+                        element: "field",
+                        attribute: "number",
+                    })?;
+                let field_type = FieldType::from_quickfix(field_el.attribute("type").ok_or(
+                    DictionaryError::MissingAttribute {
+                        element: "field",
+                        attribute: "type",
+                    },
+                )?);
+
+                fields.insert(
+                    name.clone(),
+                    Field {
+                        number,
+                        name,
+                        field_type,
+                        length_tag: None,
+                    },
+                );
+            }
+        }
+
+        let mut length_tags = Vec::new();
+        for field in fields.values() {
+            if field.field_type != FieldType::Data {
+                continue;
+            }
+            for suffix in ["Len", "Length"] {
+                let candidate = format!("{}{}", field.name, suffix);
+                if let Some(length_field) = fields.get(&candidate) {
+                    if length_field.field_type == FieldType::Int {
+                        length_tags.push((field.name.clone(), length_field.number));
+                        break;
+                    }
+                }
+            }
+        }
+        for (name, length_tag) in length_tags {
+            if let Some(field) = fields.get_mut(&name) {
+                field.length_tag = Some(length_tag);
+            }
+        }
+
+        let mut messages = Vec::new();
+        for messages_el in root.children().filter(|n| n.has_tag_name("messages")) {
+            for message_el in messages_el.children().filter(|n| n.has_tag_name("message")) {
+                let name = message_el
+                    .attribute("name")
+                    .ok_or(DictionaryError::MissingAttribute {
+                        element: "message",
+                        attribute: "name",
+                    })?
+                    .to_string();
+                let msg_type = message_el
+                    .attribute("msgtype")
+                    .ok_or(DictionaryError::MissingAttribute {
+                        element: "message",
+                        attribute: "msgtype",
+                    })?
+                    .to_string();
+
+                let mut msg_fields = Vec::new();
+                for field_el in message_el.children().filter(|n| n.has_tag_name("field")) {
+                    let field_name = field_el
+                        .attribute("name")
+                        .ok_or(DictionaryError::MissingAttribute {
+                            element: "field",
+                            attribute: "name",
+                        })?
+                        .to_string();
+                    if !fields.contains_key(&field_name) {
+                        return Err(DictionaryError::UnknownField(field_name));
+                    }
+                    let required = field_el.attribute("required").unwrap_or("N") == "Y";
+                    msg_fields.push(FieldRef {
+                        name: field_name,
+                        required,
+                    });
+                }
+
+                messages.push(Message {
+                    name,
+                    msg_type,
+                    fields: msg_fields,
+                });
+            }
+        }
+
+        Ok(Dictionary { fields, messages })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Dictionary, FieldType};
+
+    const SIMPLE: &str = r#"
+        <fix>
+            <fields>
+                <field number="35" name="MsgType" type="STRING"/>
+                <field number="38" name="OrderQty" type="QTY"/>
+                <field number="44" name="Price" type="PRICE"/>
+            </fields>
+            <messages>
+                <message name="NewOrderSingle" msgtype="D">
+                    <field name="OrderQty" required="Y"/>
+                    <field name="Price" required="N"/>
+                </message>
+            </messages>
+        </fix>
+    "#;
+
+    #[test]
+    fn parses_fields() {
+        let dict = Dictionary::parse(SIMPLE).unwrap();
+        assert_eq!(dict.fields.len(), 3);
+        assert_eq!(dict.fields["OrderQty"].number, 38);
+        assert_eq!(dict.fields["OrderQty"].field_type, FieldType::Decimal);
+        assert_eq!(dict.fields["MsgType"].field_type, FieldType::Str);
+    }
+
+    #[test]
+    fn parses_messages() {
+        let dict = Dictionary::parse(SIMPLE).unwrap();
+        assert_eq!(dict.messages.len(), 1);
+        let message = &dict.messages[0];
+        assert_eq!(message.name, "NewOrderSingle");
+        assert_eq!(message.msg_type, "D");
+        assert_eq!(message.fields.len(), 2);
+        assert!(message.fields[0].required);
+        assert!(!message.fields[1].required);
+    }
+
+    #[test]
+    fn resolves_data_field_length_tag() {
+        let xml = r#"
+            <fix>
+                <fields>
+                    <field number="90" name="SecureDataLen" type="LENGTH"/>
+                    <field number="91" name="SecureData" type="DATA"/>
+                    <field number="212" name="XmlDataLen" type="LENGTH"/>
+                    <field number="213" name="XmlData" type="DATA"/>
+                    <field number="95" name="RawDataLength" type="LENGTH"/>
+                    <field number="96" name="RawData" type="DATA"/>
+                    <field number="58" name="Text" type="STRING"/>
+                </fields>
+                <messages>
+                    <message name="Logon" msgtype="A">
+                        <field name="RawDataLength" required="N"/>
+                        <field name="RawData" required="N"/>
+                    </message>
+                </messages>
+            </fix>
+        "#;
+        let dict = Dictionary::parse(xml).unwrap();
+        assert_eq!(dict.fields["SecureData"].length_tag, Some(90));
+        assert_eq!(dict.fields["XmlData"].length_tag, Some(212));
+        assert_eq!(dict.fields["RawData"].length_tag, Some(95));
+        assert_eq!(dict.fields["Text"].length_tag, None);
+    }
+
+    #[test]
+    fn rejects_unknown_field_reference() {
+        let xml = r#"
+            <fix>
+                <fields>
+                    <field number="35" name="MsgType" type="STRING"/>
+                </fields>
+                <messages>
+                    <message name="Heartbeat" msgtype="0">
+                        <field name="TestReqID" required="N"/>
+                    </message>
+                </messages>
+            </fix>
+        "#;
+        assert!(matches!(
+            Dictionary::parse(xml),
+            Err(super::DictionaryError::UnknownField(_))
+        ));
+    }
+}
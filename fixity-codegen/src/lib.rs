@@ -0,0 +1,19 @@
+//! Build-time code generator for FIX data dictionaries.
+//!
+//! Consumes a QuickFIX-style XML data dictionary (fields, their tag numbers and types, and
+//! message layouts) and emits Rust source with a typed struct plus parser/serializer for each
+//! message, whose fields are parsed and written through `fixity_core`'s existing `FixValue`
+//! impls - in the same spirit as `pdl-compiler` turning a packet description into a generated
+//! backend. A broker's published dictionary thereby becomes directly usable, without
+//! hand-writing a parser per message. No new `FixValue` impls are generated: the message structs
+//! don't implement `FixValue`, and enumerated fields are typed by their base FIX type rather than
+//! as generated enums.
+//!
+//! Components and repeating groups are not expanded yet; a dictionary message that references one
+//! is generated with only its top-level, non-group fields.
+
+pub mod codegen;
+pub mod dictionary;
+
+pub use codegen::{generate, CodegenError};
+pub use dictionary::{Dictionary, DictionaryError};
@@ -0,0 +1,144 @@
+//! A fixed-capacity cursor for composing multiple [`FixValue::to_bytes`] writes into one buffer.
+//!
+//! Without an allocator, callers building up a message field-by-field have to guess a buffer size
+//! up front and retry on `None`. [`FixValue::MAX_LEN`] gives the exact worst case per field, so
+//! [`Buffer`] can be sized precisely and just reports [`Overflow`] if that bound is ever genuinely
+//! exceeded.
+use crate::data_types::FixValue;
+
+/// Error returned when a [`Buffer`] doesn't have enough remaining capacity for a write.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Overflow;
+
+/// A fixed-capacity, `N`-byte buffer that tracks how many bytes have been written so far, so that
+/// callers composing several [`FixValue::to_bytes`] writes don't have to juggle the cursor
+/// themselves.
+#[derive(Debug, Clone)]
+pub struct Buffer<const N: usize> {
+    data: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> Buffer<N> {
+    /// An empty buffer.
+    pub fn new() -> Self {
+        Buffer {
+            data: [0; N],
+            len: 0,
+        }
+    }
+
+    /// The buffer's total capacity, `N`.
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// The number of bytes written so far.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether any bytes have been written yet.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of bytes still available before the buffer is full.
+    pub fn remaining(&self) -> usize {
+        N - self.len
+    }
+
+    /// Directly set the write cursor, for callers that wrote into [`Buffer::as_mut_slice`] by
+    /// hand instead of going through [`Buffer::write`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is greater than [`Buffer::capacity`].
+    pub fn set_len(&mut self, len: usize) {
+        assert!(
+            len <= N,
+            "Buffer::set_len: {} exceeds capacity {}",
+            len,
+            N
+        );
+        self.len = len;
+    }
+
+    /// The bytes written so far.
+    pub fn as_slice(&self) -> &[u8] {
+        &self.data[..self.len]
+    }
+
+    /// The unwritten portion of the buffer, for a direct write followed by [`Buffer::set_len`].
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.data[self.len..]
+    }
+
+    /// Serialize `value` via [`FixValue::to_bytes`] and append it to the buffer, advancing the
+    /// cursor by the number of bytes written.
+    pub fn write<'a, T, E>(&mut self, value: &T) -> Result<usize, Overflow>
+    where
+        T: FixValue<'a, E>,
+    {
+        let written = value.to_bytes(&mut self.data[self.len..]).ok_or(Overflow)?;
+        self.len += written;
+        Ok(written)
+    }
+}
+
+impl<const N: usize> Default for Buffer<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Buffer, Overflow};
+
+    #[test]
+    fn write_simple() {
+        // u32::MAX_LEN - sized exactly to the field, per the module docs.
+        let mut buf: Buffer<10> = Buffer::new();
+        assert_eq!(buf.write(&12u32), Ok(2));
+        assert_eq!(buf.as_slice(), b"12");
+        assert_eq!(buf.len(), 2);
+        assert_eq!(buf.remaining(), buf.capacity() - 2);
+    }
+
+    #[test]
+    fn write_composes() {
+        let mut buf: Buffer<16> = Buffer::new();
+        buf.write(&12u32).unwrap();
+        buf.write(&34u32).unwrap();
+        assert_eq!(buf.as_slice(), b"1234");
+    }
+
+    #[test]
+    fn write_str_with_slack_capacity() {
+        let mut buf: Buffer<16> = Buffer::new();
+        assert_eq!(buf.write(&"ab"), Ok(2));
+        assert_eq!(buf.as_slice(), b"ab");
+    }
+
+    #[test]
+    fn write_bytes_with_slack_capacity() {
+        let mut buf: Buffer<16> = Buffer::new();
+        assert_eq!(buf.write(&&b"ab"[..]), Ok(2));
+        assert_eq!(buf.as_slice(), b"ab");
+    }
+
+    #[test]
+    fn write_overflow() {
+        let mut buf: Buffer<0> = Buffer::new();
+        assert_eq!(buf.write(&5u32), Err(Overflow));
+    }
+
+    #[test]
+    fn set_len_updates_cursor() {
+        let mut buf: Buffer<4> = Buffer::new();
+        buf.as_mut_slice()[..2].copy_from_slice(b"ab");
+        buf.set_len(2);
+        assert_eq!(buf.as_slice(), b"ab");
+    }
+}
@@ -0,0 +1,207 @@
+//! Runtime knobs for relaxing [`crate::message::message`]'s spec-conforming defaults to
+//! interoperate with non-conforming counterparties.
+//!
+//! Real-world FIX sessions don't always follow the spec to the letter: some use a custom
+//! delimiter, some zero-pad integer fields, some tack on unexpected trailing bytes. Rather than
+//! forking the parsers for a specific broker, build a [`ParserConfig`] with the knobs that broker
+//! needs and pass it to [`ParserConfig::parse_message`].
+use crate::message::{self, Message, MessageError};
+use crate::SOH;
+
+/// Policy for validating integer tag values parsed by [`ParserConfig::parse_message`] (currently
+/// only `BodyLength`; `CheckSum` is always parsed leniently, since its zero-padding is part of
+/// the wire format).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IntegerStrictness {
+    /// Reject a leading `0` digit on a multi-digit value (e.g. `007`), matching how
+    /// [`crate::wire_format::tagnum`] already treats tag numbers.
+    Strict,
+    /// Accept zero-padded integers from counterparties that don't follow the spec exactly,
+    /// matching [`crate::message::message`]'s existing behavior.
+    #[default]
+    Lenient,
+}
+
+/// Policy for bytes remaining after a message's `CheckSum` tag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TrailingBytes {
+    /// Trailing bytes after `CheckSum` are a framing error, matching
+    /// [`crate::message::message`]'s existing behavior.
+    #[default]
+    Reject,
+    /// Trailing bytes after `CheckSum` are ignored.
+    Allow,
+}
+
+/// Builder for the knobs [`message::message_with_config`] accepts, so callers can adapt to
+/// non-conforming counterparties without forking the parsers.
+///
+/// [`ParserConfig::default`] reproduces [`crate::message::message`]'s existing, spec-conforming
+/// behavior exactly; each `with_*` call relaxes one aspect of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParserConfig {
+    delimiter: u8,
+    integer_strictness: IntegerStrictness,
+    max_body_length: Option<usize>,
+    trailing_bytes: TrailingBytes,
+}
+
+impl ParserConfig {
+    /// The spec-conforming defaults: standard `SOH` delimiter, zero-padded integers accepted, no
+    /// `BodyLength` limit, and trailing bytes after `CheckSum` rejected.
+    pub fn new() -> Self {
+        ParserConfig {
+            delimiter: SOH,
+            integer_strictness: IntegerStrictness::default(),
+            max_body_length: None,
+            trailing_bytes: TrailingBytes::default(),
+        }
+    }
+
+    /// Split tags on `delimiter` instead of the standard `SOH` byte.
+    pub fn with_delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Select how strictly the `BodyLength` integer is validated. `CheckSum` is unaffected.
+    pub fn with_integer_strictness(mut self, strictness: IntegerStrictness) -> Self {
+        self.integer_strictness = strictness;
+        self
+    }
+
+    /// Reject any message whose declared `BodyLength` exceeds `max_body_length`, before the body
+    /// is sliced out of the input. Useful for bounding how much a caller reads off a socket before
+    /// a message is known to be well-formed.
+    pub fn with_max_body_length(mut self, max_body_length: usize) -> Self {
+        self.max_body_length = Some(max_body_length);
+        self
+    }
+
+    /// Select how bytes after `CheckSum` are handled.
+    pub fn with_trailing_bytes(mut self, trailing_bytes: TrailingBytes) -> Self {
+        self.trailing_bytes = trailing_bytes;
+        self
+    }
+
+    /// The delimiter tags are split on.
+    pub fn delimiter(&self) -> u8 {
+        self.delimiter
+    }
+
+    /// The configured integer validation strictness.
+    pub fn integer_strictness(&self) -> IntegerStrictness {
+        self.integer_strictness
+    }
+
+    /// The configured maximum `BodyLength`, if any.
+    pub fn max_body_length(&self) -> Option<usize> {
+        self.max_body_length
+    }
+
+    /// The configured trailing-byte policy.
+    pub fn trailing_bytes(&self) -> TrailingBytes {
+        self.trailing_bytes
+    }
+
+    /// Frame and validate a complete FIX message per this configuration; see
+    /// [`message::message_with_config`].
+    pub fn parse_message<'a>(&self, i: &'a [u8]) -> Result<Message<'a>, MessageError<'a>> {
+        message::message_with_config(i, self)
+    }
+}
+
+impl Default for ParserConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IntegerStrictness, ParserConfig, TrailingBytes};
+    use crate::message::{Message, MessageError};
+
+    #[test]
+    fn default_matches_message() {
+        let msg = b"8=FIX.4.4\x019=5\x0135=0\x0110=163\x01";
+        assert_eq!(
+            ParserConfig::default().parse_message(msg),
+            Ok(Message {
+                begin_string: b"FIX.4.4",
+                body: b"35=0\x01",
+            })
+        );
+    }
+
+    #[test]
+    fn custom_delimiter() {
+        let msg = b"8=FIX.4.4|9=5|35=0|10=020|";
+        let config = ParserConfig::new().with_delimiter(b'|');
+        assert_eq!(
+            config.parse_message(msg),
+            Ok(Message {
+                begin_string: b"FIX.4.4",
+                body: b"35=0|",
+            })
+        );
+    }
+
+    #[test]
+    fn strict_rejects_leading_zero() {
+        let msg = b"8=FIX.4.4\x019=05\x0135=0\x0110=211\x01";
+        let config = ParserConfig::new().with_integer_strictness(IntegerStrictness::Strict);
+        assert!(matches!(
+            config.parse_message(msg),
+            Err(MessageError::Field(_))
+        ));
+    }
+
+    #[test]
+    fn lenient_accepts_leading_zero() {
+        let msg = b"8=FIX.4.4\x019=05\x0135=0\x0110=211\x01";
+        let config = ParserConfig::new().with_integer_strictness(IntegerStrictness::Lenient);
+        assert_eq!(
+            config.parse_message(msg),
+            Ok(Message {
+                begin_string: b"FIX.4.4",
+                body: b"35=0\x01",
+            })
+        );
+    }
+
+    #[test]
+    fn strict_accepts_zero_padded_checksum() {
+        // CheckSum's 3-digit zero-padding is spec-required, not broker nonconformance, so it must
+        // parse under `Strict` even when it's below 100 and so genuinely zero-padded.
+        let msg = b"8=FIX.4.4\x019=11\x0135=0\x0158= e\x0110=000\x01";
+        let config = ParserConfig::new().with_integer_strictness(IntegerStrictness::Strict);
+        assert_eq!(
+            config.parse_message(msg),
+            Ok(Message {
+                begin_string: b"FIX.4.4",
+                body: b"35=0\x0158= e\x01",
+            })
+        );
+    }
+
+    #[test]
+    fn max_body_length_exceeded() {
+        let msg = b"8=FIX.4.4\x019=5\x0135=0\x0110=163\x01";
+        let config = ParserConfig::new().with_max_body_length(4);
+        assert_eq!(config.parse_message(msg), Err(MessageError::TooLarge));
+    }
+
+    #[test]
+    fn trailing_bytes_allowed() {
+        let msg = b"8=FIX.4.4\x019=5\x0135=0\x0110=163\x0158=extra\x01";
+        let config = ParserConfig::new().with_trailing_bytes(TrailingBytes::Allow);
+        assert_eq!(
+            config.parse_message(msg),
+            Ok(Message {
+                begin_string: b"FIX.4.4",
+                body: b"35=0\x01",
+            })
+        );
+    }
+}
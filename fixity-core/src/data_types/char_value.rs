@@ -0,0 +1,67 @@
+//! FIX type representing a single ASCII character, e.g. `CHAR` and `BOOLEAN` (`Y`/`N`) fields.
+use crate::data_types::{FixValue, ParseErrorKind, ParseResult, ValueError};
+use nom::error::ErrorKind;
+
+/// A single raw byte, as used by FIX's `CHAR` and `BOOLEAN` types.
+///
+/// `BOOLEAN` fields (`Y`/`N`) and enumerated `CHAR` fields are single ASCII bytes, not decimal
+/// digit strings, so they can't reuse the `u8` `FixValue` impl from
+/// [`crate::data_types::int`](super::int) - that impl parses `u8` as up to 3 decimal digits, and
+/// rejects `b"Y"` outright.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Char(pub u8);
+
+impl<'a> FixValue<'a, ValueError> for Char {
+    // Exactly one byte, always.
+    const MAX_LEN: usize = 1;
+
+    fn from_bytes(input: &[u8]) -> ParseResult<Self, ValueError> {
+        match input {
+            [byte] => Ok(Char(*byte)),
+            _ => Err(ValueError {
+                kind: ParseErrorKind::Char,
+                nom_kind: ErrorKind::Eof,
+            }),
+        }
+    }
+
+    fn to_bytes(&self, buf: &mut [u8]) -> Option<usize> {
+        if buf.is_empty() {
+            return None;
+        }
+        buf[0] = self.0;
+        Some(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Char;
+    use crate::data_types::{FixValue, ParseErrorKind, ValueError};
+
+    #[test]
+    fn from_bytes_single_byte() {
+        let value: Char = FixValue::from_bytes(b"Y").unwrap();
+        assert_eq!(value, Char(b'Y'));
+    }
+
+    #[test]
+    fn from_bytes_rejects_empty() {
+        let result: Result<Char, ValueError> = FixValue::from_bytes(b"");
+        assert_eq!(result.unwrap_err().kind, ParseErrorKind::Char);
+    }
+
+    #[test]
+    fn from_bytes_rejects_multiple_bytes() {
+        let result: Result<Char, ValueError> = FixValue::from_bytes(b"YN");
+        assert_eq!(result.unwrap_err().kind, ParseErrorKind::Char);
+    }
+
+    #[test]
+    fn to_bytes_simple() {
+        let value = Char(b'N');
+        let buffer = &mut [0u8; 1][..];
+        assert_eq!(value.to_bytes(buffer), Some(1));
+        assert_eq!(buffer, b"N");
+    }
+}
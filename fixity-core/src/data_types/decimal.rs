@@ -0,0 +1,252 @@
+//! FIX type representing exact, fixed-point decimal values
+use crate::data_types::{FixValue, ParseErrorKind, ParseResult};
+use crate::wire_format::{byte, itos};
+use nom::character::complete::digit0;
+use nom::combinator::{all_consuming, opt};
+use nom::sequence::{preceded, tuple};
+
+/// A decimal value represented as an `i64` mantissa and the number of digits, `scale`, that fall
+/// after the decimal point.
+///
+/// FIX `PRICE`/`QTY`/`AMT` fields are exact-precision decimals; round-tripping them through `f64`
+/// can silently change the digits that get sent over the wire. `Decimal` instead parses and
+/// serializes the digits directly, the same way the `integral_value!` macro handles plain
+/// integers, so callers get lossless round trips for monetary values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Decimal {
+    /// The value's significant digits, with the decimal point removed and the sign folded in.
+    pub mantissa: i64,
+    /// The number of `mantissa` digits that fall after the decimal point.
+    pub scale: u8,
+}
+
+impl<'a> FixValue<'a, ParseErrorKind> for Decimal {
+    // Worst case is a negative mantissa whose scale is at least its digit count, serialized as
+    // `-0.` followed by exactly `scale` fractional bytes (zero padding plus the mantissa's
+    // digits): 1 (sign) + 2 ("0.") + `u8::MAX` (fractional bytes).
+    const MAX_LEN: usize = 3 + u8::MAX as usize;
+
+    fn from_bytes(input: &[u8]) -> ParseResult<Self> {
+        all_consuming(tuple((
+            opt(byte(b'-')),
+            digit0,
+            opt(preceded(byte(b'.'), digit0)),
+        )))(input)
+        .map_err(|_: nom::Err<nom::error::Error<&[u8]>>| ParseErrorKind::Decimal)
+        .and_then(|(_, (sign, int_digits, frac_digits)): (_, (Option<u8>, &[u8], Option<&[u8]>))| {
+            let frac_digits = frac_digits.unwrap_or(&[][..]);
+            if int_digits.is_empty() && frac_digits.is_empty() {
+                return Err(ParseErrorKind::Decimal);
+            }
+            if frac_digits.len() > u8::MAX as usize {
+                return Err(ParseErrorKind::Decimal);
+            }
+
+            let mut mantissa: i64 = 0;
+            for d in int_digits.iter().chain(frac_digits.iter()) {
+                mantissa = mantissa
+                    .checked_mul(10)
+                    .and_then(|m| m.checked_add((*d - b'0') as i64))
+                    .ok_or(ParseErrorKind::Decimal)?;
+            }
+            if sign.is_some() {
+                mantissa = -mantissa;
+            }
+
+            Ok(Decimal {
+                mantissa,
+                scale: frac_digits.len() as u8,
+            })
+        })
+    }
+
+    fn to_bytes(&self, buf: &mut [u8]) -> Option<usize> {
+        // i64::MIN..=i64::MAX needs at most 19 digits plus a sign byte.
+        let mut digits_buf = [0u8; 20];
+        let written = itos(self.mantissa, &mut digits_buf)?;
+        let mut digits = &digits_buf[..written];
+
+        let negative = digits[0] == b'-';
+        if negative {
+            digits = &digits[1..];
+        }
+
+        let scale = self.scale as usize;
+        let int_len = digits.len().saturating_sub(scale);
+
+        let mut index = 0;
+        macro_rules! push {
+            ($b:expr) => {{
+                if index >= buf.len() {
+                    return None;
+                }
+                buf[index] = $b;
+                index += 1;
+            }};
+        }
+
+        if negative {
+            push!(b'-');
+        }
+
+        if scale == 0 {
+            for &d in digits {
+                push!(d);
+            }
+            return Some(index);
+        }
+
+        if int_len > 0 {
+            for &d in &digits[..int_len] {
+                push!(d);
+            }
+        } else {
+            push!(b'0');
+        }
+
+        push!(b'.');
+
+        for _ in 0..scale.saturating_sub(digits.len()) {
+            push!(b'0');
+        }
+        for &d in &digits[int_len..] {
+            push!(d);
+        }
+
+        Some(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::decimal::Decimal;
+    use crate::data_types::{FixValue, ParseErrorKind};
+
+    #[test]
+    fn from_bytes_integer() {
+        let value: Decimal = FixValue::from_bytes(b"123").unwrap();
+        assert_eq!(
+            value,
+            Decimal {
+                mantissa: 123,
+                scale: 0
+            }
+        );
+    }
+
+    #[test]
+    fn from_bytes_fraction() {
+        let value: Decimal = FixValue::from_bytes(b"123.45").unwrap();
+        assert_eq!(
+            value,
+            Decimal {
+                mantissa: 12345,
+                scale: 2
+            }
+        );
+    }
+
+    #[test]
+    fn from_bytes_leading_dot() {
+        let value: Decimal = FixValue::from_bytes(b".5").unwrap();
+        assert_eq!(
+            value,
+            Decimal {
+                mantissa: 5,
+                scale: 1
+            }
+        );
+    }
+
+    #[test]
+    fn from_bytes_trailing_dot() {
+        let value: Decimal = FixValue::from_bytes(b"5.").unwrap();
+        assert_eq!(
+            value,
+            Decimal {
+                mantissa: 5,
+                scale: 0
+            }
+        );
+    }
+
+    #[test]
+    fn from_bytes_negative() {
+        let value: Decimal = FixValue::from_bytes(b"-123.45").unwrap();
+        assert_eq!(
+            value,
+            Decimal {
+                mantissa: -12345,
+                scale: 2
+            }
+        );
+    }
+
+    #[test]
+    fn from_bytes_leading_zeros() {
+        let value: Decimal = FixValue::from_bytes(b"007.50").unwrap();
+        assert_eq!(
+            value,
+            Decimal {
+                mantissa: 750,
+                scale: 2
+            }
+        );
+    }
+
+    #[test]
+    fn from_bytes_empty() {
+        let result: Result<Decimal, ParseErrorKind> = FixValue::from_bytes(b"");
+        assert_eq!(result, Err(ParseErrorKind::Decimal));
+    }
+
+    #[test]
+    fn from_bytes_double_dot() {
+        let result: Result<Decimal, ParseErrorKind> = FixValue::from_bytes(b"1.2.3");
+        assert_eq!(result, Err(ParseErrorKind::Decimal));
+    }
+
+    #[test]
+    fn to_bytes_simple() {
+        let value = Decimal {
+            mantissa: 12345,
+            scale: 2,
+        };
+        let buffer = &mut [0u8; 6][..];
+        assert_eq!(value.to_bytes(buffer), Some(6));
+        assert_eq!(buffer, b"123.45");
+    }
+
+    #[test]
+    fn to_bytes_negative() {
+        let value = Decimal {
+            mantissa: -12345,
+            scale: 2,
+        };
+        let buffer = &mut [0u8; 7][..];
+        assert_eq!(value.to_bytes(buffer), Some(7));
+        assert_eq!(buffer, b"-123.45");
+    }
+
+    #[test]
+    fn to_bytes_scale_exceeds_digits() {
+        let value = Decimal {
+            mantissa: 5,
+            scale: 3,
+        };
+        let buffer = &mut [0u8; 5][..];
+        assert_eq!(value.to_bytes(buffer), Some(5));
+        assert_eq!(buffer, b"0.005");
+    }
+
+    #[test]
+    fn to_bytes_no_scale() {
+        let value = Decimal {
+            mantissa: 123,
+            scale: 0,
+        };
+        let buffer = &mut [0u8; 3][..];
+        assert_eq!(value.to_bytes(buffer), Some(3));
+        assert_eq!(buffer, b"123");
+    }
+}
@@ -1,16 +1,31 @@
 //! FIX types representing integral values
-use crate::data_types::{FixValue, ParseResult, ParseErrorKind};
+use crate::data_types::{FixValue, ParseErrorKind, ParseResult, ValueError};
 use crate::wire_format::{atoi, itos, u_atoi, u_itos};
 use nom::combinator::all_consuming;
+use nom::error::ErrorKind;
+
+fn nom_kind<I>(e: nom::Err<nom::error::Error<I>>) -> ErrorKind {
+    match e {
+        nom::Err::Error(e) | nom::Err::Failure(e) => e.code,
+        nom::Err::Incomplete(_) => ErrorKind::Complete,
+    }
+}
 
 macro_rules! integral_value {
-    ($parser:expr, $serializer:expr, $($t:ty)*, $err:expr) => {
+    ($parser:expr, $serializer:expr, $err:expr, $(($t:ty, $max_len:expr)),* $(,)?) => {
         $(
-            impl<'a> FixValue<'a, ParseErrorKind> for $t {
-                fn from_bytes(input: &[u8]) -> ParseResult<Self> {
+            impl<'a> FixValue<'a, ValueError> for $t {
+                // The maximum number of ASCII digits (plus a sign, for signed types) $t can
+                // serialize to, i.e. the digit count of `$t::MIN`/`$t::MAX`.
+                const MAX_LEN: usize = $max_len;
+
+                fn from_bytes(input: &[u8]) -> ParseResult<Self, ValueError> {
                     all_consuming($parser)(input)
                         .map(|(_, v)| v)
-                        .map_err(|_| $err)
+                        .map_err(|e| ValueError {
+                            kind: $err,
+                            nom_kind: nom_kind(e),
+                        })
                 }
 
                 fn to_bytes(&self, buf: &mut [u8]) -> Option<usize> {
@@ -21,5 +36,21 @@ macro_rules! integral_value {
     };
 }
 
-integral_value!(u_atoi, u_itos, u8 u16 u32 u64, ParseErrorKind::UnsignedInteger);
-integral_value!(atoi, itos, i8 i16 i32 i64, ParseErrorKind::SignedInteger);
+integral_value!(
+    u_atoi,
+    u_itos,
+    ParseErrorKind::UnsignedInteger,
+    (u8, 3),
+    (u16, 5),
+    (u32, 10),
+    (u64, 20),
+);
+integral_value!(
+    atoi,
+    itos,
+    ParseErrorKind::SignedInteger,
+    (i8, 4),
+    (i16, 6),
+    (i32, 11),
+    (i64, 20),
+);
@@ -1,4 +1,6 @@
 //! De/Serialization between FIX data types and Rust's native types.
+pub mod char_value;
+pub mod decimal;
 pub mod int;
 pub mod slice;
 
@@ -26,6 +28,8 @@ pub mod slice;
 ///     TWAP,
 /// }
 /// impl<'a> FixValue<'a, ()> for AlgoType {
+///     const MAX_LEN: usize = 1;
+///
 ///     fn from_bytes(input: &[u8]) -> ParseResult<Self, ()> {
 ///         if input.len() != 1 {
 ///             return Err(());
@@ -66,6 +70,14 @@ pub trait FixValue<'a, E>
 where
     Self: Sized,
 {
+    /// The maximum number of bytes [`FixValue::to_bytes`] can ever write for this type.
+    ///
+    /// This lets callers size a stack buffer exactly instead of guessing and retrying on `None`;
+    /// see [`crate::buffer::Buffer`]. Types with no fixed bound on their serialized size (e.g. a
+    /// pass-through `&str` or `&[u8]`) should set this to `usize::MAX` to signal that no useful
+    /// bound exists.
+    const MAX_LEN: usize;
+
     /// Deserialize a FIX value type from a byte buffer. The buffer will contain all bytes in
     /// between the key-value separator (`=`) and tag delimiter (`SOH` by default).
     fn from_bytes(input: &'a [u8]) -> ParseResult<Self, E>;
@@ -86,13 +98,31 @@ pub enum ParseErrorKind {
     UnsignedInteger,
     /// Error while deserializing a (potentially) signed integer
     SignedInteger,
+    /// Error while deserializing a fixed-point [`decimal::Decimal`]
+    Decimal,
     /// Error while deserializing a byte slice into UTF-8 string
     String,
+    /// Error while deserializing a single-byte [`char_value::Char`]
+    Char,
 }
 
 /// Result type for deserializing FIX values into the corresponding native types.
 pub type ParseResult<O, E = ParseErrorKind> = Result<O, E>;
 
+/// A [`ParseErrorKind`] paired with the specific nom rule that failed underneath it.
+///
+/// This is deliberately a single layer of context, rather than an accumulating stack of nom
+/// errors: without `alloc`, there's nowhere to grow an unbounded error stack, so `ValueError`
+/// just wraps the coarse [`ParseErrorKind`] classification with the one extra detail that's
+/// cheap to carry along - which nom combinator actually rejected the input.
+#[derive(Debug, Eq, PartialEq)]
+pub struct ValueError {
+    /// Coarse classification of what went wrong.
+    pub kind: ParseErrorKind,
+    /// The nom combinator that failed while parsing the value.
+    pub nom_kind: nom::error::ErrorKind,
+}
+
 #[cfg(test)]
 mod tests {
     use crate::data_types::FixValue;
@@ -1,18 +1,27 @@
 //! FIX value types
 
-use crate::data_types::{FixValue, ParseResult, ParseErrorKind};
+use crate::data_types::{FixValue, ParseResult, ParseErrorKind, ValueError};
 use core::str::from_utf8;
+use nom::error::ErrorKind;
 
 // Note: According to the FIX data type definition, string types must not contain the delimiter
 // character. However, we don't check for the delimiter here because there's no way for the message
 // parser to give us a invalid value; an invalid delimiter in the message corrupts the entire
 // message, not just this value.
-impl<'a> FixValue<'a, ParseErrorKind> for &'a str {
-    fn from_bytes(input: &'a [u8]) -> ParseResult<Self, ParseErrorKind> {
+impl<'a> FixValue<'a, ValueError> for &'a str {
+    // Strings have no fixed maximum length.
+    const MAX_LEN: usize = usize::MAX;
+
+    fn from_bytes(input: &'a [u8]) -> ParseResult<Self, ValueError> {
         // While strings in FIX are required to be ASCII by the protocol definition, I'm not sure
         // if this is respected in practice.
         // If users need alternate encodings, they should implement `FixValue` on a wrapper type.
-        from_utf8(input).map_err(|_| ParseErrorKind::String)
+        from_utf8(input).map_err(|_| ValueError {
+            kind: ParseErrorKind::String,
+            // There's no nom combinator underneath a UTF-8 validity check; `Verify` is the
+            // closest nom has to "a predicate over the input failed."
+            nom_kind: ErrorKind::Verify,
+        })
     }
 
     fn to_bytes(&self, buf: &mut [u8]) -> Option<usize> {
@@ -21,7 +30,7 @@ impl<'a> FixValue<'a, ParseErrorKind> for &'a str {
         // If users need alternate encodings, they should implement `FixValue` on a wrapper type.
         let self_bytes = self.as_bytes();
         if buf.len() >= self_bytes.len() {
-            buf.copy_from_slice(self_bytes);
+            buf[..self_bytes.len()].copy_from_slice(self_bytes);
             Some(self_bytes.len())
         } else {
             None
@@ -30,13 +39,16 @@ impl<'a> FixValue<'a, ParseErrorKind> for &'a str {
 }
 
 impl<'a> FixValue<'a, ()> for &'a [u8] {
+    // Byte slices have no fixed maximum length.
+    const MAX_LEN: usize = usize::MAX;
+
     fn from_bytes(input: &'a [u8]) -> ParseResult<Self, ()> {
         Ok(input)
     }
 
     fn to_bytes(&self, buf: &mut [u8]) -> Option<usize> {
         if buf.len() >= self.len() {
-            buf.copy_from_slice(self);
+            buf[..self.len()].copy_from_slice(self);
             Some(self.len())
         } else {
             None
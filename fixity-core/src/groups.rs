@@ -0,0 +1,284 @@
+//! Repeating-group parsing: a "NoXXX" count tag followed by that many repetitions of an ordered
+//! set of member tags, optionally containing nested repeating groups of their own.
+//!
+//! A repeating group can't be split into independent tags the way [`crate::wire_format::Tags`]
+//! splits a flat message body, because a group's *members* aren't known from the wire alone -
+//! they're part of the message's static schema. [`GroupSchema`] describes that schema (the
+//! group's delimiter tag, its other member tags, and any nested groups among them), and
+//! [`repeating_group`] uses it to carve `count` repetitions out of a tag stream, the same way
+//! [`crate::wire_format::data_tag_delimited`] uses an externally-parsed length to carve out a data
+//! tag's value.
+use crate::wire_format::tag;
+use nom::error::ErrorKind;
+use nom::{IResult, Offset};
+
+/// Maximum number of member tags (not counting the delimiter tag) a single [`GroupSchema`] can
+/// describe.
+const MAX_MEMBER_TAGS: usize = 16;
+
+/// Maximum number of nested-group schemas a single [`GroupSchema`] can reference.
+const MAX_NESTED_GROUPS: usize = 4;
+
+/// Errors produced while iterating a repeating group with [`GroupEntries`].
+#[derive(Debug, Eq, PartialEq)]
+pub enum GroupError {
+    /// The underlying tag/value stream could not be parsed while scanning for an entry's
+    /// boundary.
+    Malformed,
+    /// A nested group's count tag value could not be parsed as an integer.
+    InvalidNestedCount,
+}
+
+/// Describes the shape of one repeating group: its delimiter (first, required) tag, the rest of
+/// the tags that belong to one entry, and any nested groups whose count tag appears among those
+/// members.
+///
+/// A new entry is recognized by the recurrence of the delimiter tag; any tag that is neither the
+/// delimiter, a registered member, nor a nested group's count tag ends the current entry (and,
+/// once `count` entries have been seen, the group as a whole).
+#[derive(Debug, Clone, Copy)]
+pub struct GroupSchema<'s> {
+    delimiter_tag: u16,
+    member_tags: [u16; MAX_MEMBER_TAGS],
+    member_len: usize,
+    nested: [Option<(u16, &'s GroupSchema<'s>)>; MAX_NESTED_GROUPS],
+    nested_len: usize,
+}
+
+impl<'s> GroupSchema<'s> {
+    /// An otherwise-empty schema for a group whose entries start with `delimiter_tag`.
+    pub fn new(delimiter_tag: u16) -> Self {
+        GroupSchema {
+            delimiter_tag,
+            member_tags: [0; MAX_MEMBER_TAGS],
+            member_len: 0,
+            nested: [None; MAX_NESTED_GROUPS],
+            nested_len: 0,
+        }
+    }
+
+    /// Register `tag` as a member of this group's entries, in addition to the delimiter tag.
+    ///
+    /// # Panics
+    ///
+    /// Panics if more than `MAX_MEMBER_TAGS` member tags are registered.
+    pub fn with_member(mut self, tag: u16) -> Self {
+        assert!(self.member_len < MAX_MEMBER_TAGS, "GroupSchema is full");
+        self.member_tags[self.member_len] = tag;
+        self.member_len += 1;
+        self
+    }
+
+    /// Register a nested repeating group whose count tag is `count_tag`, described by `schema`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if more than `MAX_NESTED_GROUPS` nested groups are registered.
+    pub fn with_nested_group(mut self, count_tag: u16, schema: &'s GroupSchema<'s>) -> Self {
+        assert!(self.nested_len < MAX_NESTED_GROUPS, "GroupSchema is full");
+        self.nested[self.nested_len] = Some((count_tag, schema));
+        self.nested_len += 1;
+        self
+    }
+
+    fn is_member(&self, candidate: u16) -> bool {
+        candidate == self.delimiter_tag
+            || self.member_tags[..self.member_len].contains(&candidate)
+            || self.nested_for(candidate).is_some()
+    }
+
+    fn nested_for(&self, candidate: u16) -> Option<&'s GroupSchema<'s>> {
+        self.nested[..self.nested_len]
+            .iter()
+            .find_map(|entry| match entry {
+                Some((count_tag, schema)) if *count_tag == candidate => Some(*schema),
+                _ => None,
+            })
+    }
+}
+
+/// Consume one entry of `schema` from the front of `remaining`, which must start with `schema`'s
+/// delimiter tag. Returns the entry's own byte span and whatever's left afterward.
+fn next_entry<'a>(
+    remaining: &'a [u8],
+    schema: &GroupSchema,
+) -> Result<(&'a [u8], &'a [u8]), GroupError> {
+    let (mut rest, delimiter) = tag(remaining).map_err(|_| GroupError::Malformed)?;
+    if delimiter.tag != schema.delimiter_tag {
+        return Err(GroupError::Malformed);
+    }
+
+    while !rest.is_empty() {
+        let (after, t) = match tag(rest) {
+            Ok(v) => v,
+            Err(_) => break,
+        };
+
+        if t.tag == schema.delimiter_tag {
+            break;
+        }
+
+        if let Some(nested_schema) = schema.nested_for(t.tag) {
+            let count: usize = core::str::from_utf8(t.value)
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .ok_or(GroupError::InvalidNestedCount)?;
+
+            rest = after;
+            for _ in 0..count {
+                let (_, after_nested) = next_entry(rest, nested_schema)?;
+                rest = after_nested;
+            }
+            continue;
+        }
+
+        if schema.is_member(t.tag) {
+            rest = after;
+            continue;
+        }
+
+        break;
+    }
+
+    let consumed = remaining.offset(rest);
+    Ok((&remaining[..consumed], rest))
+}
+
+/// Parse `count` repetitions of `schema` from the front of a tag stream, the way
+/// [`crate::wire_format::data_tag_delimited`] parses a data tag given its externally-known
+/// length.
+///
+/// ```rust
+/// # use fixity_core::groups::{repeating_group, GroupSchema};
+/// let schema = GroupSchema::new(80).with_member(81);
+/// let body = b"80=ACC1\x0181=100\x0180=ACC2\x0181=200\x0199=done\x01";
+///
+/// let (rem, mut entries) = repeating_group(2, schema)(body).unwrap();
+/// assert_eq!(entries.next(), Some(Ok(&b"80=ACC1\x0181=100\x01"[..])));
+/// assert_eq!(entries.next(), Some(Ok(&b"80=ACC2\x0181=200\x01"[..])));
+/// assert_eq!(entries.next(), None);
+/// assert_eq!(rem, b"99=done\x01");
+/// ```
+pub fn repeating_group<'s>(
+    count: usize,
+    schema: GroupSchema<'s>,
+) -> impl Fn(&[u8]) -> IResult<&[u8], GroupEntries<'_, 's>> {
+    move |i: &[u8]| {
+        let mut remaining = i;
+        for _ in 0..count {
+            match next_entry(remaining, &schema) {
+                Ok((_, rest)) => remaining = rest,
+                Err(_) => {
+                    return Err(nom::Err::Error(nom::error::Error::new(i, ErrorKind::Count)))
+                }
+            }
+        }
+
+        let group_len = i.offset(remaining);
+        Ok((
+            remaining,
+            GroupEntries::new(&i[..group_len], count, schema),
+        ))
+    }
+}
+
+/// Iterator over the successive entries of one repeating group, each yielded as the raw `&[u8]`
+/// byte span covering that entry's tags - including any nested group it contains, still encoded
+/// as ordinary tag/value pairs within that span.
+///
+/// Constructed by [`repeating_group`].
+pub struct GroupEntries<'a, 's> {
+    remaining: &'a [u8],
+    schema: GroupSchema<'s>,
+    entries_left: usize,
+}
+
+impl<'a, 's> GroupEntries<'a, 's> {
+    fn new(payload: &'a [u8], count: usize, schema: GroupSchema<'s>) -> Self {
+        GroupEntries {
+            remaining: payload,
+            schema,
+            entries_left: count,
+        }
+    }
+}
+
+impl<'a, 's> Iterator for GroupEntries<'a, 's> {
+    type Item = Result<&'a [u8], GroupError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.entries_left == 0 {
+            return None;
+        }
+
+        match next_entry(self.remaining, &self.schema) {
+            Ok((entry, rest)) => {
+                self.remaining = rest;
+                self.entries_left -= 1;
+                Some(Ok(entry))
+            }
+            Err(e) => {
+                self.entries_left = 0;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{repeating_group, GroupSchema};
+
+    #[test]
+    fn simple_group() {
+        let schema = GroupSchema::new(80).with_member(81);
+        let body = b"80=ACC1\x0181=100\x0180=ACC2\x0181=200\x0199=done\x01";
+
+        let (rem, mut entries) = repeating_group(2, schema)(body).unwrap();
+        assert_eq!(entries.next(), Some(Ok(&b"80=ACC1\x0181=100\x01"[..])));
+        assert_eq!(entries.next(), Some(Ok(&b"80=ACC2\x0181=200\x01"[..])));
+        assert_eq!(entries.next(), None);
+        assert_eq!(rem, b"99=done\x01");
+    }
+
+    #[test]
+    fn nested_group() {
+        let inner = GroupSchema::new(671).with_member(672);
+        let outer = GroupSchema::new(600)
+            .with_member(601)
+            .with_nested_group(670, &inner);
+
+        let body = b"\
+600=IBM\x01601=10\x01670=2\x01671=ACC1\x01672=5\x01671=ACC2\x01672=5\x01\
+600=MSFT\x01601=20\x01\
+700=END\x01";
+
+        let (rem, mut entries) = repeating_group(2, outer)(&body[..]).unwrap();
+        assert_eq!(
+            entries.next(),
+            Some(Ok(&b"600=IBM\x01601=10\x01670=2\x01671=ACC1\x01672=5\x01671=ACC2\x01672=5\x01"[..]))
+        );
+        assert_eq!(entries.next(), Some(Ok(&b"600=MSFT\x01601=20\x01"[..])));
+        assert_eq!(entries.next(), None);
+        assert_eq!(rem, b"700=END\x01");
+    }
+
+    #[test]
+    fn missing_entry_is_malformed() {
+        let schema = GroupSchema::new(80).with_member(81);
+        let body = b"80=ACC1\x0181=100\x0199=done\x01";
+        assert!(repeating_group(2, schema)(body).is_err());
+    }
+
+    #[test]
+    fn invalid_nested_count() {
+        // The group-level scan runs eagerly (to compute the correct remaining-bytes slice for
+        // further parsing), so a schema mismatch like this surfaces immediately rather than
+        // lazily through the entry iterator.
+        let inner = GroupSchema::new(671).with_member(672);
+        let outer = GroupSchema::new(600).with_nested_group(670, &inner);
+
+        let body = b"600=IBM\x01670=abc\x01";
+        assert!(repeating_group(1, outer)(&body[..]).is_err());
+    }
+}
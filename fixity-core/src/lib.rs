@@ -4,7 +4,13 @@
 #![no_std]
 #![deny(missing_docs)]
 
+pub mod buffer;
+pub mod config;
 pub mod data_types;
+pub mod groups;
+pub mod message;
+pub mod stream;
+pub mod tag_table;
 pub mod wire_format;
 
 /// The default FIX delimiter token
@@ -0,0 +1,209 @@
+//! Full FIX message framing.
+//!
+//! A raw byte buffer read off the wire isn't safe to hand to [`crate::wire_format::Tags`]
+//! directly: until `BeginString`, `BodyLength`, and `CheckSum` have been validated, there's no
+//! guarantee the buffer actually contains one complete, well-formed message rather than a
+//! truncated one or noise. [`message`] is the "first phase" described in the `wire_format` module
+//! docs - it frames a single message and checks both invariants before handing back the body for
+//! further (tag-by-tag) parsing.
+use crate::config::{IntegerStrictness, ParserConfig, TrailingBytes};
+use crate::data_types::{ParseErrorKind, ValueError};
+use crate::wire_format::{tag_delimited, u_atoi, u_atoi_strict, RawTag, TagParseError, Tags};
+use nom::bytes::complete::take;
+use nom::combinator::all_consuming;
+use nom::{IResult, Offset};
+use num_traits::{FromPrimitive, PrimInt};
+
+/// Tag number for `BeginString`.
+pub const BEGIN_STRING: u16 = 8;
+/// Tag number for `BodyLength`.
+pub const BODY_LENGTH: u16 = 9;
+/// Tag number for `CheckSum`.
+pub const CHECK_SUM: u16 = 10;
+
+/// Errors that can occur while framing a complete FIX message.
+#[derive(Debug, Eq, PartialEq)]
+pub enum MessageError<'a> {
+    /// The message's tags were not structured as `BeginString`, `BodyLength`, `<body>`,
+    /// `CheckSum`, in that order.
+    Malformed,
+    /// The `BodyLength` or `CheckSum` value itself could not be parsed as an integer.
+    Field(TagParseError<'a>),
+    /// The declared `BodyLength` runs past the end of the input. A `BodyLength` that fits in the
+    /// input but doesn't end right before a `CheckSum` tag is reported as [`Self::Malformed`].
+    LengthMismatch,
+    /// The declared `BodyLength` exceeded [`ParserConfig::max_body_length`].
+    TooLarge,
+    /// The computed checksum did not match the value declared in `CheckSum`.
+    ChecksumMismatch,
+}
+
+/// A fully-framed FIX message: the `BeginString` value plus the raw, unparsed body bytes, with
+/// the `BodyLength` and `CheckSum` invariants already confirmed.
+#[derive(Debug, PartialEq)]
+pub struct Message<'a> {
+    /// Raw value of tag 8 (`BeginString`), e.g. `b"FIX.4.4"`.
+    pub begin_string: &'a [u8],
+    /// The message body: every tag between `BodyLength` and `CheckSum`, unparsed.
+    pub body: &'a [u8],
+}
+
+impl<'a> Message<'a> {
+    /// Iterate over the tag/value pairs contained in the message body.
+    pub fn tags(&self) -> Tags<'a> {
+        Tags::new(self.body)
+    }
+}
+
+fn nom_kind<I>(e: nom::Err<nom::error::Error<I>>) -> nom::error::ErrorKind {
+    match e {
+        nom::Err::Error(e) | nom::Err::Failure(e) => e.code,
+        nom::Err::Incomplete(_) => nom::error::ErrorKind::Complete,
+    }
+}
+
+/// Parse `raw`'s value as an unsigned integer per `strictness`, enriching any failure with the
+/// tag number, raw value, and byte offset within `message` - the same shape
+/// [`crate::wire_format::parse_tagged`] produces for `FixValue` fields.
+fn parse_uint_tag<'a, T>(
+    message: &'a [u8],
+    raw: &RawTag<'a>,
+    strictness: IntegerStrictness,
+) -> Result<T, TagParseError<'a>>
+where
+    T: PrimInt + FromPrimitive,
+{
+    let result = match strictness {
+        IntegerStrictness::Strict => all_consuming(u_atoi_strict::<T>)(raw.value),
+        IntegerStrictness::Lenient => all_consuming(u_atoi::<T>)(raw.value),
+    };
+    result.map(|(_, v)| v).map_err(|e| TagParseError {
+        tag: raw.tag,
+        value: raw.value,
+        offset: message.offset(&raw.value),
+        cause: ValueError {
+            kind: ParseErrorKind::UnsignedInteger,
+            nom_kind: nom_kind(e),
+        },
+    })
+}
+
+/// Parse and validate a complete FIX message from a raw byte buffer using the spec-conforming
+/// defaults; see [`message_with_config`] to relax them for a non-conforming counterparty.
+///
+/// `BodyLength` (tag 9) must count every byte starting immediately after the `SOH` terminating
+/// tag 9 up to and including the `SOH` preceding tag 10; the `CheckSum` (tag 10) must equal the
+/// sum of every message byte up through that same `SOH`, taken mod 256.
+pub fn message(i: &[u8]) -> Result<Message, MessageError> {
+    message_with_config(i, &ParserConfig::default())
+}
+
+/// Parse and validate a complete FIX message from a raw byte buffer, per `config`.
+///
+/// See [`message`] for the `BodyLength`/`CheckSum` invariants this enforces, and
+/// [`ParserConfig`] for how `config` can relax them.
+pub fn message_with_config<'a>(
+    i: &'a [u8],
+    config: &ParserConfig,
+) -> Result<Message<'a>, MessageError<'a>> {
+    let tag_parser = tag_delimited(config.delimiter());
+
+    let (after_begin, begin_string_tag) = tag_parser(i).map_err(|_| MessageError::Malformed)?;
+    if begin_string_tag.tag != BEGIN_STRING {
+        return Err(MessageError::Malformed);
+    }
+
+    let (after_length, body_length_tag) =
+        tag_parser(after_begin).map_err(|_| MessageError::Malformed)?;
+    if body_length_tag.tag != BODY_LENGTH {
+        return Err(MessageError::Malformed);
+    }
+    let body_length: u32 =
+        parse_uint_tag(i, &body_length_tag, config.integer_strictness()).map_err(MessageError::Field)?;
+
+    if let Some(max) = config.max_body_length() {
+        if body_length as usize > max {
+            return Err(MessageError::TooLarge);
+        }
+    }
+
+    let take_body: IResult<&[u8], &[u8]> = take(body_length as usize)(after_length);
+    let (before_checksum, body) = take_body.map_err(|_| MessageError::LengthMismatch)?;
+
+    let (rem, checksum_tag) = tag_parser(before_checksum).map_err(|_| MessageError::Malformed)?;
+    if checksum_tag.tag != CHECK_SUM {
+        return Err(MessageError::Malformed);
+    }
+    if config.trailing_bytes() == TrailingBytes::Reject && !rem.is_empty() {
+        return Err(MessageError::Malformed);
+    }
+    // CheckSum's 3-digit zero-padding (`10=006`) is mandated by the wire format itself, not a
+    // broker nonconformance `IntegerStrictness::Strict` should reject - so it's always parsed
+    // leniently, regardless of `config.integer_strictness()`.
+    let expected_checksum: u8 =
+        parse_uint_tag(i, &checksum_tag, IntegerStrictness::Lenient).map_err(MessageError::Field)?;
+
+    let checksummed_region = &i[..i.len() - before_checksum.len()];
+    let computed_checksum = checksummed_region
+        .iter()
+        .fold(0u8, |acc, b| acc.wrapping_add(*b));
+    if computed_checksum != expected_checksum {
+        return Err(MessageError::ChecksumMismatch);
+    }
+
+    Ok(Message {
+        begin_string: begin_string_tag.value,
+        body,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{message, Message, MessageError};
+
+    // Checksum of b"8=FIX.4.4\x019=5\x0135=0\x01" (sum of bytes mod 256) is 163.
+    #[test]
+    fn message_simple() {
+        let msg = b"8=FIX.4.4\x019=5\x0135=0\x0110=163\x01";
+        assert_eq!(
+            message(msg),
+            Ok(Message {
+                begin_string: b"FIX.4.4",
+                body: b"35=0\x01",
+            })
+        );
+    }
+
+    #[test]
+    fn message_length_mismatch() {
+        let msg = b"8=FIX.4.4\x019=50\x0135=0\x0110=000\x01";
+        assert_eq!(message(msg), Err(MessageError::LengthMismatch));
+    }
+
+    #[test]
+    fn message_checksum_mismatch() {
+        let msg = b"8=FIX.4.4\x019=5\x0135=0\x0110=000\x01";
+        assert_eq!(message(msg), Err(MessageError::ChecksumMismatch));
+    }
+
+    #[test]
+    fn message_wrong_leading_tag() {
+        let msg = b"9=5\x0135=0\x0110=000\x01";
+        assert_eq!(message(msg), Err(MessageError::Malformed));
+    }
+
+    #[test]
+    fn message_trailing_bytes() {
+        let msg = b"8=FIX.4.4\x019=5\x0135=0\x0110=163\x0158=extra\x01";
+        assert_eq!(message(msg), Err(MessageError::Malformed));
+    }
+
+    #[test]
+    fn message_malformed_body_length() {
+        let msg = b"8=FIX.4.4\x019=abc\x0135=0\x0110=163\x01";
+        match message(msg) {
+            Err(MessageError::Field(err)) => assert_eq!(err.tag, super::BODY_LENGTH),
+            other => panic!("expected a Field error, got {:?}", other),
+        }
+    }
+}
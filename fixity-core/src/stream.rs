@@ -0,0 +1,172 @@
+//! Cursor types for serializing/deserializing a sequence of [`FixValue`]s without manual offset
+//! arithmetic.
+//!
+//! [`FixWriter`] and [`FixReader`] borrow a caller-owned buffer and track a position into it, so
+//! building up (or reading back) a whole message becomes a sequence of `write()`/`read()` calls
+//! with automatic position advancement and a single overflow check per call, instead of
+//! hand-threading a byte offset through every [`FixValue::to_bytes`]/[`FixValue::from_bytes`]
+//! call. The byte-slice methods on [`FixValue`] remain the right tool for a single one-shot
+//! value; these two types are the multi-field composition layer on top of them.
+use crate::buffer::Overflow;
+use crate::data_types::FixValue;
+
+/// A write cursor over a caller-owned buffer.
+///
+/// See the [module docs](self) for how this relates to [`FixValue::to_bytes`].
+pub struct FixWriter<'a> {
+    buf: &'a mut [u8],
+    pos: usize,
+}
+
+impl<'a> FixWriter<'a> {
+    /// Wrap `buf` in a writer starting at position `0`.
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        FixWriter { buf, pos: 0 }
+    }
+
+    /// The number of bytes written so far.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// The number of bytes still available before the underlying buffer is full.
+    pub fn remaining(&self) -> usize {
+        self.buf.len() - self.pos
+    }
+
+    /// The bytes written so far.
+    pub fn written(&self) -> &[u8] {
+        &self.buf[..self.pos]
+    }
+
+    /// Serialize `value` via [`FixValue::to_bytes`] at the current position, advancing the
+    /// cursor by the number of bytes written.
+    pub fn write<'v, T, E>(&mut self, value: &T) -> Result<usize, Overflow>
+    where
+        T: FixValue<'v, E>,
+    {
+        let written = value.to_bytes(&mut self.buf[self.pos..]).ok_or(Overflow)?;
+        self.pos += written;
+        Ok(written)
+    }
+}
+
+/// Error returned by [`FixReader::read`]: either the buffer ran out before `len` bytes were
+/// available, or the bytes that were available didn't parse into the requested type.
+#[derive(Debug, Eq, PartialEq)]
+pub enum ReadError<E> {
+    /// Fewer than `len` bytes remained in the buffer.
+    Overflow,
+    /// The requested slice didn't parse into the requested [`FixValue`] type.
+    Value(E),
+}
+
+/// A read cursor over a caller-owned buffer.
+///
+/// Unlike [`FixWriter`], [`FixReader::read`] needs to be told how many bytes the next value
+/// occupies - [`FixValue::from_bytes`] has no way to discover a value's length on its own, the
+/// same way [`crate::wire_format::tag`] finds it via the `SOH` delimiter at the wire-format layer.
+/// See the [module docs](self) for more.
+pub struct FixReader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> FixReader<'a> {
+    /// Wrap `buf` in a reader starting at position `0`.
+    pub fn new(buf: &'a [u8]) -> Self {
+        FixReader { buf, pos: 0 }
+    }
+
+    /// The number of bytes read so far.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// The number of bytes not yet read.
+    pub fn remaining(&self) -> usize {
+        self.buf.len() - self.pos
+    }
+
+    /// Deserialize the next `len` bytes via [`FixValue::from_bytes`], advancing the cursor by
+    /// `len`.
+    pub fn read<T, E>(&mut self, len: usize) -> Result<T, ReadError<E>>
+    where
+        T: FixValue<'a, E>,
+    {
+        if len > self.remaining() {
+            return Err(ReadError::Overflow);
+        }
+
+        let slice = &self.buf[self.pos..self.pos + len];
+        let value = T::from_bytes(slice).map_err(ReadError::Value)?;
+        self.pos += len;
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FixReader, FixWriter, ReadError};
+    use crate::buffer::Overflow;
+
+    #[test]
+    fn writer_composes() {
+        let mut buf = [0u8; 8];
+        let mut writer = FixWriter::new(&mut buf);
+
+        assert_eq!(writer.write(&12u32), Ok(2));
+        assert_eq!(writer.write(&34u32), Ok(2));
+        assert_eq!(writer.position(), 4);
+        assert_eq!(writer.written(), b"1234");
+    }
+
+    #[test]
+    fn writer_writes_str_and_bytes_with_slack_capacity() {
+        let mut buf = [0u8; 8];
+        let mut writer = FixWriter::new(&mut buf);
+
+        assert_eq!(writer.write(&"ab"), Ok(2));
+        assert_eq!(writer.write(&&b"cd"[..]), Ok(2));
+        assert_eq!(writer.written(), b"abcd");
+    }
+
+    #[test]
+    fn writer_overflow() {
+        let mut buf = [0u8; 0];
+        let mut writer = FixWriter::new(&mut buf);
+        assert_eq!(writer.write(&5u32), Err(Overflow));
+    }
+
+    #[test]
+    fn reader_reads_back_what_writer_wrote() {
+        let mut buf = [0u8; 8];
+        let mut writer = FixWriter::new(&mut buf);
+        writer.write(&12u32).unwrap();
+        writer.write(&345u32).unwrap();
+
+        let mut reader = FixReader::new(&buf[..5]);
+        let first: u32 = reader.read(2).unwrap();
+        let second: u32 = reader.read(3).unwrap();
+        assert_eq!(first, 12);
+        assert_eq!(second, 345);
+        assert_eq!(reader.position(), 5);
+        assert_eq!(reader.remaining(), 0);
+    }
+
+    #[test]
+    fn reader_overflow() {
+        let buf = b"12";
+        let mut reader = FixReader::new(buf);
+        let result: Result<u32, ReadError<_>> = reader.read(3);
+        assert_eq!(result, Err(ReadError::Overflow));
+    }
+
+    #[test]
+    fn reader_value_error() {
+        let buf = b"ab";
+        let mut reader = FixReader::new(buf);
+        let result: Result<u32, ReadError<_>> = reader.read(2);
+        assert!(matches!(result, Err(ReadError::Value(_))));
+    }
+}
@@ -0,0 +1,319 @@
+//! Compiled, zero-copy tag -> value-type lookup table.
+//!
+//! A real FIX dictionary maps hundreds of tags to their expected value type, but re-parsing that
+//! dictionary (typically XML) on every process start to answer "what type is tag 44" is wasted
+//! work a long-running session pays for nothing. Taking a cue from `regex-automata`'s wire
+//! format, [`TagTypeTable`] instead treats that mapping as a small, fixed binary encoding - tag
+//! number, expected [`TagType`], and (for `Data` tags) the length tag that precedes them, sorted
+//! by ascending tag - that [`TagTypeTable::from_bytes`] can load straight out of an embedded
+//! `&'static [u8]` or an `mmap`'d dictionary file: a length check and a binary search, with no
+//! allocation and no re-parsing.
+//!
+//! [`TagTableBuilder`] is the other half: it compiles a sequence of `(tag, type)` registrations,
+//! in the same ascending order [`TagTypeTable::lookup`] relies on, into that binary encoding.
+use core::cmp::Ordering;
+use core::convert::TryInto;
+
+/// Byte alignment [`TagTypeTable::from_bytes`] requires of its input.
+///
+/// Nothing in this module's read path actually casts the bytes to a wider type - entries are
+/// decoded a byte at a time with [`u16::from_le_bytes`] - so misalignment can't cause undefined
+/// behavior here. The check exists anyway so the wire format stays compatible with a future
+/// zero-copy fast path (casting the entry bytes directly to `&[u16]`), and so a table embedded
+/// without `#[repr(align(2))]` on its containing `static` fails loudly instead of silently
+/// working until someone adds that fast path.
+const TABLE_ALIGN: usize = 2;
+
+/// Encoded size, in bytes, of one [`TagTypeEntry`].
+const ENTRY_LEN: usize = 6;
+
+/// The `FixValue` category a tag's value is expected to parse as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagType {
+    /// Parse with an unsigned-integer `FixValue` impl.
+    UnsignedInteger,
+    /// Parse with a signed-integer `FixValue` impl.
+    SignedInteger,
+    /// Parse with [`crate::data_types::decimal::Decimal`].
+    Decimal,
+    /// Parse as a UTF-8 string.
+    String,
+    /// A length-prefixed data tag (see [`crate::wire_format::data_tag`]); the tag carrying its
+    /// length is [`TagTypeEntry::length_tag`].
+    Data,
+}
+
+impl TagType {
+    fn to_u8(self) -> u8 {
+        match self {
+            TagType::UnsignedInteger => 0,
+            TagType::SignedInteger => 1,
+            TagType::Decimal => 2,
+            TagType::String => 3,
+            TagType::Data => 4,
+        }
+    }
+
+    fn from_u8(v: u8) -> Option<Self> {
+        match v {
+            0 => Some(TagType::UnsignedInteger),
+            1 => Some(TagType::SignedInteger),
+            2 => Some(TagType::Decimal),
+            3 => Some(TagType::String),
+            4 => Some(TagType::Data),
+            _ => None,
+        }
+    }
+}
+
+/// One compiled tag -> type mapping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TagTypeEntry {
+    /// The tag number this entry describes.
+    pub tag: u16,
+    /// The value type expected for `tag`.
+    pub value_type: TagType,
+    /// For a [`TagType::Data`] tag, the tag number carrying its length; `None` otherwise.
+    pub length_tag: Option<u16>,
+}
+
+impl TagTypeEntry {
+    fn encode(&self, out: &mut [u8; ENTRY_LEN]) {
+        out[0..2].copy_from_slice(&self.tag.to_le_bytes());
+        out[2] = self.value_type.to_u8();
+        out[3] = 0;
+        out[4..6].copy_from_slice(&self.length_tag.unwrap_or(0).to_le_bytes());
+    }
+
+    fn decode(bytes: &[u8; ENTRY_LEN]) -> Option<Self> {
+        let tag = u16::from_le_bytes(bytes[0..2].try_into().unwrap());
+        let value_type = TagType::from_u8(bytes[2])?;
+        let length_tag = u16::from_le_bytes(bytes[4..6].try_into().unwrap());
+        Some(TagTypeEntry {
+            tag,
+            value_type,
+            length_tag: if length_tag == 0 {
+                None
+            } else {
+                Some(length_tag)
+            },
+        })
+    }
+}
+
+/// Errors produced while loading a compiled table with [`TagTypeTable::from_bytes`].
+#[derive(Debug, Eq, PartialEq)]
+pub enum TableError {
+    /// The input's address was not aligned to [`TABLE_ALIGN`].
+    Misaligned,
+    /// The input was shorter than a header, or its length didn't match the entry count the
+    /// header declares.
+    Truncated,
+}
+
+/// A compiled tag -> type table, borrowed directly from its encoded bytes with no allocation or
+/// copying.
+///
+/// The encoding is a 2-byte little-endian entry count, followed by that many 6-byte
+/// `(tag: u16, value_type: u8, reserved: u8, length_tag: u16)` entries sorted by ascending `tag`,
+/// produced by [`TagTableBuilder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TagTypeTable<'a> {
+    bytes: &'a [u8],
+    count: usize,
+}
+
+impl<'a> TagTypeTable<'a> {
+    /// Load a compiled table from `bytes`, e.g. an embedded `&'static [u8]` or an `mmap`'d
+    /// dictionary file.
+    ///
+    /// This only checks `bytes`'s alignment and that its length matches the entry count declared
+    /// in the header - both constant-time regardless of how many entries the table holds.
+    /// Individual entries are decoded lazily by [`TagTypeTable::lookup`] rather than validated up
+    /// front, so loading a table never re-parses it the way building one from an XML dictionary
+    /// would.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, TableError> {
+        if bytes.as_ptr() as usize % TABLE_ALIGN != 0 {
+            return Err(TableError::Misaligned);
+        }
+        if bytes.len() < 2 {
+            return Err(TableError::Truncated);
+        }
+        let count = u16::from_le_bytes(bytes[0..2].try_into().unwrap()) as usize;
+        if bytes.len() != 2 + count * ENTRY_LEN {
+            return Err(TableError::Truncated);
+        }
+
+        Ok(TagTypeTable { bytes, count })
+    }
+
+    fn entry(&self, index: usize) -> Option<TagTypeEntry> {
+        let start = 2 + index * ENTRY_LEN;
+        let raw: &[u8; ENTRY_LEN] = self.bytes[start..start + ENTRY_LEN].try_into().ok()?;
+        TagTypeEntry::decode(raw)
+    }
+
+    /// Look up the expected type for `tag`, binary searching the table's sorted entries.
+    ///
+    /// Returns `None` if `tag` isn't present, or if a malformed entry is encountered while
+    /// searching - a corrupt table fails lookups rather than panicking.
+    pub fn lookup(&self, tag: u16) -> Option<TagTypeEntry> {
+        let mut lo = 0usize;
+        let mut hi = self.count;
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let entry = self.entry(mid)?;
+            match entry.tag.cmp(&tag) {
+                Ordering::Equal => return Some(entry),
+                Ordering::Less => lo = mid + 1,
+                Ordering::Greater => hi = mid,
+            }
+        }
+        None
+    }
+}
+
+/// The number of bytes a [`TagTableBuilder`] needs to compile `entry_count` entries.
+pub const fn encoded_len(entry_count: usize) -> usize {
+    2 + entry_count * ENTRY_LEN
+}
+
+/// Compiles a sequence of tag -> type registrations into the binary encoding
+/// [`TagTypeTable::from_bytes`] reads.
+///
+/// Entries must be pushed in ascending `tag` order, matching the order [`TagTypeTable::lookup`]
+/// relies on to binary search; an out-of-order registration is rejected immediately rather than
+/// silently producing a table that can't be searched correctly.
+pub struct TagTableBuilder<'a> {
+    buf: &'a mut [u8],
+    count: usize,
+    last_tag: Option<u16>,
+}
+
+impl<'a> TagTableBuilder<'a> {
+    /// Begin compiling a table into `buf`, which must be at least [`encoded_len`] bytes for the
+    /// number of entries that will be pushed.
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        TagTableBuilder {
+            buf,
+            count: 0,
+            last_tag: None,
+        }
+    }
+
+    /// Register `tag`'s expected type, and - for a [`TagType::Data`] tag - the tag carrying its
+    /// length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tag` does not exceed the previously-registered tag, or if `buf` has no room
+    /// left for another entry.
+    pub fn push(&mut self, tag: u16, value_type: TagType, length_tag: Option<u16>) {
+        if let Some(last) = self.last_tag {
+            assert!(tag > last, "tags must be registered in ascending order");
+        }
+
+        let start = 2 + self.count * ENTRY_LEN;
+        let end = start + ENTRY_LEN;
+        assert!(end <= self.buf.len(), "buf has no room for another entry");
+
+        let mut raw = [0u8; ENTRY_LEN];
+        TagTypeEntry {
+            tag,
+            value_type,
+            length_tag,
+        }
+        .encode(&mut raw);
+        self.buf[start..end].copy_from_slice(&raw);
+
+        self.last_tag = Some(tag);
+        self.count += 1;
+    }
+
+    /// Finish compiling, writing the entry-count header and returning the encoded table as a
+    /// borrow of (a prefix of) `buf`.
+    pub fn finish(self) -> &'a [u8] {
+        let count = self.count as u16;
+        self.buf[0..2].copy_from_slice(&count.to_le_bytes());
+        &self.buf[..2 + self.count * ENTRY_LEN]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{encoded_len, TableError, TagTableBuilder, TagType, TagTypeEntry, TagTypeTable};
+
+    #[repr(align(2))]
+    struct Aligned<const N: usize>([u8; N]);
+
+    #[test]
+    fn roundtrip() {
+        let mut storage = Aligned([0u8; 32]);
+        let mut builder = TagTableBuilder::new(&mut storage.0[..encoded_len(3)]);
+        builder.push(8, TagType::String, None);
+        builder.push(9, TagType::UnsignedInteger, None);
+        builder.push(96, TagType::Data, Some(95));
+        let bytes = builder.finish();
+
+        let table = TagTypeTable::from_bytes(bytes).unwrap();
+        assert_eq!(
+            table.lookup(8),
+            Some(TagTypeEntry {
+                tag: 8,
+                value_type: TagType::String,
+                length_tag: None,
+            })
+        );
+        assert_eq!(
+            table.lookup(96),
+            Some(TagTypeEntry {
+                tag: 96,
+                value_type: TagType::Data,
+                length_tag: Some(95),
+            })
+        );
+        assert_eq!(table.lookup(44), None);
+    }
+
+    #[test]
+    fn rejects_truncated_header() {
+        let storage = Aligned([0u8; 1]);
+        assert_eq!(
+            TagTypeTable::from_bytes(&storage.0[..]),
+            Err(TableError::Truncated)
+        );
+    }
+
+    #[test]
+    fn rejects_length_mismatch() {
+        let mut storage = Aligned([0u8; 32]);
+        let mut builder = TagTableBuilder::new(&mut storage.0[..encoded_len(1)]);
+        builder.push(8, TagType::String, None);
+        let bytes = builder.finish();
+
+        // Truncate one byte off the end of an otherwise-valid table.
+        assert_eq!(
+            TagTypeTable::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(TableError::Truncated)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "ascending order")]
+    fn builder_rejects_out_of_order_tags() {
+        let mut storage = Aligned([0u8; 32]);
+        let mut builder = TagTableBuilder::new(&mut storage.0[..encoded_len(2)]);
+        builder.push(9, TagType::UnsignedInteger, None);
+        builder.push(8, TagType::String, None);
+    }
+
+    #[test]
+    fn empty_table_misses_everything() {
+        let mut storage = Aligned([0u8; 2]);
+        let builder = TagTableBuilder::new(&mut storage.0[..encoded_len(0)]);
+        let bytes = builder.finish();
+
+        let table = TagTypeTable::from_bytes(bytes).unwrap();
+        assert_eq!(table.lookup(8), None);
+    }
+}
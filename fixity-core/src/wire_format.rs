@@ -10,13 +10,16 @@
 //! Because we have to be robust to properly-formed messages that contain illegal values,
 //! parsing a FIX message happens in multiple phases; the first phase simply splits up each tag
 //! (key-value pair), message content validation occurs later.
+use crate::data_types::{FixValue, ValueError};
 use crate::SOH;
 use nom::bytes::complete::{is_a, take, take_till1};
+use nom::bytes::streaming::{take as take_streaming, take_till1 as take_till1_streaming};
 use nom::character::complete::digit1;
+use nom::character::streaming::digit1 as digit1_streaming;
 use nom::combinator::{map_res, opt, verify};
 use nom::error::ErrorKind;
 use nom::sequence::tuple;
-use nom::IResult;
+use nom::{IResult, Offset};
 use num_traits::{FromPrimitive, PrimInt, Signed, ToPrimitive};
 
 const ASCII_DIGITS: [u8; 10] = [
@@ -47,6 +50,14 @@ pub(crate) fn byte(b: u8) -> impl Fn(&[u8]) -> IResult<&[u8], u8> {
     move |i: &[u8]| verify(take(1_u8), |i: &[u8]| i[0] == b)(i).map(|(i, v)| (i, v[0]))
 }
 
+/// Streaming counterpart to [`byte`]: returns `Err::Incomplete` rather than `Err::Error` when the
+/// input is exhausted before a byte is available to check.
+pub(crate) fn byte_streaming(b: u8) -> impl Fn(&[u8]) -> IResult<&[u8], u8> {
+    move |i: &[u8]| {
+        verify(take_streaming(1_u8), |i: &[u8]| i[0] == b)(i).map(|(i, v)| (i, v[0]))
+    }
+}
+
 pub(crate) fn atoi<T>(i: &[u8]) -> IResult<&[u8], T>
 where
     T: PrimInt + Signed + FromPrimitive,
@@ -137,6 +148,53 @@ where
     map_res(digit1, unsigned_atoi)(i)
 }
 
+/// Strict counterpart to [`u_atoi`]: also rejects a leading `0` digit on a multi-digit value
+/// (e.g. `007`), the same restriction [`tagnum`] already applies to tag numbers. Used by
+/// [`crate::config::IntegerStrictness::Strict`].
+pub(crate) fn u_atoi_strict<T>(i: &[u8]) -> IResult<&[u8], T>
+where
+    T: PrimInt + FromPrimitive,
+{
+    let unsigned_atoi = |digits: &[u8]| -> Result<T, ErrorKind> {
+        if digits.len() > 1 && digits[0] == b'0' {
+            return Err(ErrorKind::Verify);
+        }
+
+        let mut value = T::zero();
+        for d in digits {
+            value = value
+                .checked_mul(&T::from_u8(10).unwrap())
+                .ok_or(ErrorKind::TooLarge)?;
+            value = value
+                .checked_add(&T::from_u8(*d - b'0').unwrap())
+                .ok_or(ErrorKind::TooLarge)?;
+        }
+        Ok(value)
+    };
+    map_res(digit1, unsigned_atoi)(i)
+}
+
+/// Streaming counterpart to [`u_atoi`]: returns `Err::Incomplete` rather than `Err::Error` when
+/// the digit run might not be complete yet (i.e. more digits could still arrive).
+pub(crate) fn u_atoi_streaming<T>(i: &[u8]) -> IResult<&[u8], T>
+where
+    T: PrimInt + FromPrimitive,
+{
+    let unsigned_atoi = |digits: &[u8]| -> Result<T, ErrorKind> {
+        let mut value = T::zero();
+        for d in digits {
+            value = value
+                .checked_mul(&T::from_u8(10).unwrap())
+                .ok_or(ErrorKind::TooLarge)?;
+            value = value
+                .checked_add(&T::from_u8(*d - b'0').unwrap())
+                .ok_or(ErrorKind::TooLarge)?;
+        }
+        Ok(value)
+    };
+    map_res(digit1_streaming, unsigned_atoi)(i)
+}
+
 pub(crate) fn u_itos<T>(value: T, buf: &mut [u8]) -> Option<usize>
     where
         T: PrimInt + FromPrimitive + ToPrimitive
@@ -182,6 +240,18 @@ pub(crate) fn tagnum(i: &[u8]) -> IResult<&[u8], u16> {
     .map(|(i, v)| (i, v.1))
 }
 
+/// Streaming counterpart to [`tagnum`]: returns `Err::Incomplete` when the tag number's digit run
+/// can't yet be confirmed complete from the bytes available so far. The leading-zero check is not
+/// streaming and never returns `Err::Incomplete`.
+pub(crate) fn tagnum_streaming(i: &[u8]) -> IResult<&[u8], u16> {
+    // Tagnum is an unsigned `atoi` that doesn't accept leading zeros
+    tuple((
+        verify(opt(is_a(&b"0"[..])), |i: &Option<_>| i.is_none()),
+        u_atoi_streaming::<u16>,
+    ))(i)
+    .map(|(i, v)| (i, v.1))
+}
+
 /// Base FIX protocol tag/value pair
 #[derive(Debug, PartialEq)]
 pub struct RawTag<'a> {
@@ -191,6 +261,38 @@ pub struct RawTag<'a> {
     pub value: &'a [u8],
 }
 
+/// A value-parsing failure enriched with the offending tag number, its raw value bytes, and the
+/// byte offset within the overall message at which that value begins.
+///
+/// A bare [`crate::data_types::ValueError`] is enough to say *what* went wrong, but tracking down
+/// *where* inside a 300-field message that happened means knowing the tag and its location too;
+/// `TagParseError` is what [`parse_tagged`] attaches that context to.
+#[derive(Debug, Eq, PartialEq)]
+pub struct TagParseError<'a> {
+    /// The FIX tag number whose value failed to parse.
+    pub tag: u16,
+    /// The raw, unparsed value bytes that failed to parse.
+    pub value: &'a [u8],
+    /// Byte offset of `value` within the message it was parsed from.
+    pub offset: usize,
+    /// The underlying classification of what went wrong.
+    pub cause: ValueError,
+}
+
+/// Parse a tag's value via its [`FixValue`] implementation, enriching any failure with the tag
+/// number, raw value, and byte offset within `message`.
+pub fn parse_tagged<'a, T>(message: &'a [u8], raw: &RawTag<'a>) -> Result<T, TagParseError<'a>>
+where
+    T: FixValue<'a, ValueError>,
+{
+    T::from_bytes(raw.value).map_err(|cause| TagParseError {
+        tag: raw.tag,
+        value: raw.value,
+        offset: message.offset(&raw.value),
+        cause,
+    })
+}
+
 /// Parse a simple FIX tag using a custom delimiter.
 pub fn tag_delimited(delimiter: u8) -> impl Fn(&[u8]) -> IResult<&[u8], RawTag> {
     move |i: &[u8]| {
@@ -222,10 +324,246 @@ pub fn data_tag(payload: &[u8], len: usize) -> IResult<&[u8], RawTag> {
     data_tag_delimited(SOH, len)(payload)
 }
 
+/// Streaming counterpart to [`tag_delimited`], for use when the input may be a partial read off a
+/// socket. Rather than failing outright when the delimiter hasn't arrived yet, this returns
+/// `Err::Incomplete(Needed)` so a caller can accumulate more bytes and retry.
+pub fn tag_delimited_streaming(delimiter: u8) -> impl Fn(&[u8]) -> IResult<&[u8], RawTag> {
+    move |i: &[u8]| {
+        tuple((
+            tagnum_streaming,
+            byte_streaming(b'='),
+            take_till1_streaming(|c| c == delimiter),
+            byte_streaming(delimiter),
+        ))(i)
+        .map(|(i, (tag, _, value, _))| (i, RawTag { tag, value }))
+    }
+}
+
+/// Streaming counterpart to [`tag`]: parse a simple FIX tag using the standard ASCII `SOH`
+/// delimiter, signalling `Err::Incomplete` rather than a hard error on a buffer underrun.
+pub fn tag_streaming(payload: &[u8]) -> IResult<&[u8], RawTag> {
+    tag_delimited_streaming(SOH)(payload)
+}
+
+/// Streaming counterpart to [`data_tag_delimited`], for use when the input may be a partial read
+/// off a socket. Since the length is already known up front, the value is taken as exactly `len`
+/// bytes; `Err::Incomplete` is returned when the tag number, the `=`, those `len` value bytes, or
+/// the trailing delimiter byte haven't fully arrived yet.
+pub fn data_tag_delimited_streaming(
+    delimiter: u8,
+    len: usize,
+) -> impl Fn(&[u8]) -> IResult<&[u8], RawTag> {
+    move |i: &[u8]| {
+        tuple((
+            tagnum_streaming,
+            byte_streaming(b'='),
+            take_streaming(len),
+            byte_streaming(delimiter),
+        ))(i)
+        .map(|(i, (tag, _, value, _))| (i, RawTag { tag, value }))
+    }
+}
+
+/// Streaming counterpart to [`data_tag`]: parse a data FIX tag using the standard ASCII `SOH`
+/// delimiter, signalling `Err::Incomplete` rather than a hard error on a buffer underrun.
+pub fn data_tag_streaming(payload: &[u8], len: usize) -> IResult<&[u8], RawTag> {
+    data_tag_delimited_streaming(SOH, len)(payload)
+}
+
+/// Iterator over the tag/value pairs making up a FIX message body, splitting on the standard
+/// ASCII `SOH` delimiter one [`RawTag`] at a time.
+///
+/// Parsing stops, without producing a final `Some(Err(..))` item, the moment a malformed tag is
+/// encountered; callers that need to distinguish "ran out of tags" from "hit a malformed tag"
+/// should check whether [`Tags::remaining`] is empty once iteration ends.
+pub struct Tags<'a> {
+    remaining: &'a [u8],
+}
+
+impl<'a> Tags<'a> {
+    /// Construct an iterator over the tags contained in `payload`.
+    pub fn new(payload: &'a [u8]) -> Self {
+        Tags { remaining: payload }
+    }
+
+    /// The bytes not yet consumed by the iterator. Non-empty after iteration ends only when a
+    /// malformed tag stopped parsing early.
+    pub fn remaining(&self) -> &'a [u8] {
+        self.remaining
+    }
+}
+
+impl<'a> Iterator for Tags<'a> {
+    type Item = RawTag<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+
+        let (rem, raw_tag) = tag(self.remaining).ok()?;
+        self.remaining = rem;
+        Some(raw_tag)
+    }
+}
+
+/// Maximum number of length/data tag pairs a single [`DataTagTable`] can hold.
+const MAX_DATA_TAG_PAIRS: usize = 8;
+
+/// A configurable mapping of "length tag" to the "data tag" whose value it describes the length
+/// of (e.g. `RawDataLength` (95) -> `RawData` (96)).
+///
+/// Data tags are allowed to contain the delimiter byte within their value, so unlike an ordinary
+/// tag their length has to be known ahead of time; [`DataTags`] uses this table to recognize when
+/// a length tag has just been seen and the following data tag should be parsed with [`data_tag`]
+/// instead of [`tag`].
+#[derive(Debug, Clone)]
+pub struct DataTagTable {
+    pairs: [(u16, u16); MAX_DATA_TAG_PAIRS],
+    len: usize,
+}
+
+impl DataTagTable {
+    /// An empty table with no length/data tag pairs registered.
+    pub fn empty() -> Self {
+        DataTagTable {
+            pairs: [(0, 0); MAX_DATA_TAG_PAIRS],
+            len: 0,
+        }
+    }
+
+    /// Register a new length tag / data tag pair.
+    ///
+    /// # Panics
+    ///
+    /// Panics if more than `MAX_DATA_TAG_PAIRS` pairs are registered.
+    pub fn register(&mut self, length_tag: u16, data_tag: u16) {
+        assert!(self.len < MAX_DATA_TAG_PAIRS, "DataTagTable is full");
+        self.pairs[self.len] = (length_tag, data_tag);
+        self.len += 1;
+    }
+
+    fn data_tag_for(&self, length_tag: u16) -> Option<u16> {
+        self.pairs[..self.len]
+            .iter()
+            .find(|(l, _)| *l == length_tag)
+            .map(|(_, d)| *d)
+    }
+
+    fn is_data_tag(&self, candidate: u16) -> bool {
+        self.pairs[..self.len].iter().any(|(_, d)| *d == candidate)
+    }
+}
+
+impl Default for DataTagTable {
+    /// A table pre-populated with the standard FIX length/data tag pairs: `SecureDataLen`(90) ->
+    /// `SecureData`(91), `RawDataLength`(95) -> `RawData`(96), and `XmlDataLen`(212) ->
+    /// `XmlData`(213).
+    fn default() -> Self {
+        let mut table = Self::empty();
+        table.register(90, 91);
+        table.register(95, 96);
+        table.register(212, 213);
+        table
+    }
+}
+
+/// Errors produced while iterating a message body with [`DataTags`].
+#[derive(Debug, Eq, PartialEq)]
+pub enum DataTagError {
+    /// A data tag appeared without a preceding length tag to say how long its value is.
+    MissingLength,
+    /// The underlying tag/value stream could not be parsed.
+    Malformed,
+}
+
+/// Iterator over the tag/value pairs making up a FIX message body, resolving length-prefixed data
+/// tags (e.g. `RawDataLength`/`RawData`) using a [`DataTagTable`] so that values containing
+/// embedded `SOH` bytes are still parsed correctly.
+///
+/// ```rust
+/// # use fixity_core::wire_format::{DataTagTable, DataTags, RawTag};
+/// let message = b"95=5\x0196=a\x01b\x01c\x01".as_ref();
+/// let mut tags = DataTags::new(message, DataTagTable::default());
+///
+/// assert_eq!(tags.next(), Some(Ok(RawTag { tag: 95, value: b"5" })));
+/// assert_eq!(tags.next(), Some(Ok(RawTag { tag: 96, value: b"a\x01b\x01c" })));
+/// assert_eq!(tags.next(), None);
+/// ```
+pub struct DataTags<'a> {
+    remaining: &'a [u8],
+    table: DataTagTable,
+    pending: Option<(u16, usize)>,
+}
+
+impl<'a> DataTags<'a> {
+    /// Construct an iterator over `payload`, pairing length and data tags according to `table`.
+    pub fn new(payload: &'a [u8], table: DataTagTable) -> Self {
+        DataTags {
+            remaining: payload,
+            table,
+            pending: None,
+        }
+    }
+}
+
+impl<'a> Iterator for DataTags<'a> {
+    type Item = Result<RawTag<'a>, DataTagError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+
+        let tag_num = match tagnum(self.remaining) {
+            Ok((_, t)) => t,
+            Err(_) => return Some(Err(DataTagError::Malformed)),
+        };
+
+        if let Some((expected_data_tag, len)) = self.pending.take() {
+            if tag_num == expected_data_tag {
+                return match data_tag(self.remaining, len) {
+                    Ok((rem, t)) => {
+                        self.remaining = rem;
+                        Some(Ok(t))
+                    }
+                    Err(_) => Some(Err(DataTagError::Malformed)),
+                };
+            }
+        }
+
+        if self.table.is_data_tag(tag_num) {
+            return Some(Err(DataTagError::MissingLength));
+        }
+
+        match tag(self.remaining) {
+            Ok((rem, t)) => {
+                self.remaining = rem;
+                if let Some(data_tag_num) = self.table.data_tag_for(t.tag) {
+                    if let Some(len) = core::str::from_utf8(t.value)
+                        .ok()
+                        .and_then(|s| s.parse().ok())
+                    {
+                        self.pending = Some((data_tag_num, len));
+                    }
+                }
+                Some(Ok(t))
+            }
+            Err(_) => Some(Err(DataTagError::Malformed)),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{atoi, byte, tagnum, u_atoi};
-    use crate::wire_format::{data_tag_delimited, tag_delimited, RawTag, itos};
+    use super::{atoi, byte, tagnum, u_atoi, u_atoi_strict};
+    use crate::data_types::ValueError;
+    use crate::wire_format::{
+        data_tag_delimited, data_tag_delimited_streaming, tag_delimited, tag_delimited_streaming,
+        itos, parse_tagged, DataTagError, DataTagTable, DataTags, RawTag, Tags,
+    };
+    use nom::error::ErrorKind;
+    use nom::{Err, Needed};
 
     #[test]
     fn byte_simple() {
@@ -292,6 +630,18 @@ mod tests {
         assert_eq!(u_atoi(b"1|234"), Ok((&b"|234"[..], 1)));
     }
 
+    #[test]
+    fn u_atoi_strict_simple() {
+        assert_eq!(u_atoi_strict(b"1234"), Ok((&b""[..], 1234)));
+        assert_eq!(u_atoi_strict(b"0"), Ok((&b""[..], 0)));
+    }
+
+    #[test]
+    fn u_atoi_strict_rejects_leading_zero() {
+        assert!(u_atoi_strict::<u32>(b"0123").is_err());
+        assert!(u_atoi_strict::<u32>(b"01").is_err());
+    }
+
     #[test]
     fn tagnum_simple() {
         assert_eq!(tagnum(b"1234"), Ok((&b""[..], 1234)));
@@ -365,6 +715,178 @@ mod tests {
         assert!(data_tag_delimited(b'|', 7)(b"8=FIX.4.4").is_err())
     }
 
+    #[test]
+    fn tag_delimited_streaming_simple() {
+        assert_eq!(
+            tag_delimited_streaming(b'|')(b"8=FIX.4.4|"),
+            Ok((
+                &b""[..],
+                RawTag {
+                    tag: 8,
+                    value: b"FIX.4.4"
+                }
+            ))
+        )
+    }
+
+    #[test]
+    fn tag_delimited_streaming_missing_delimiter() {
+        assert_eq!(
+            tag_delimited_streaming(b'|')(b"8=FIX.4.4"),
+            Err(Err::Incomplete(Needed::new(1)))
+        )
+    }
+
+    #[test]
+    fn data_delimited_streaming_simple() {
+        assert_eq!(
+            data_tag_delimited_streaming(b'|', 7)(b"8=FIX.4.4|"),
+            Ok((
+                &b""[..],
+                RawTag {
+                    tag: 8,
+                    value: b"FIX.4.4"
+                }
+            ))
+        )
+    }
+
+    #[test]
+    fn data_delimited_streaming_missing_delimiter() {
+        assert_eq!(
+            data_tag_delimited_streaming(b'|', 7)(b"8=FIX.4.4"),
+            Err(Err::Incomplete(Needed::new(1)))
+        )
+    }
+
+    #[test]
+    fn data_delimited_streaming_needs_more_data() {
+        assert_eq!(
+            data_tag_delimited_streaming(b'|', 8)(b"8=FIX.4.4|"),
+            Err(Err::Incomplete(Needed::new(1)))
+        )
+    }
+
+    #[test]
+    fn tags_simple() {
+        let message = b"8=FIX.4.4\x019=5\x0135=0\x01";
+        let mut tags = Tags::new(&message[..]);
+        assert_eq!(
+            tags.next(),
+            Some(RawTag {
+                tag: 8,
+                value: b"FIX.4.4"
+            })
+        );
+        assert_eq!(tags.next(), Some(RawTag { tag: 9, value: b"5" }));
+        assert_eq!(tags.next(), Some(RawTag { tag: 35, value: b"0" }));
+        assert_eq!(tags.next(), None);
+    }
+
+    #[test]
+    fn tags_stops_on_malformed() {
+        let message = b"8=FIX.4.4\x01garbage";
+        let mut tags = Tags::new(&message[..]);
+        assert_eq!(
+            tags.next(),
+            Some(RawTag {
+                tag: 8,
+                value: b"FIX.4.4"
+            })
+        );
+        assert_eq!(tags.next(), None);
+        assert_eq!(tags.remaining(), b"garbage");
+    }
+
+    #[test]
+    fn data_tags_standard_pair() {
+        let message = b"95=5\x0196=a\x01b\x01c\x01";
+        let mut tags = DataTags::new(&message[..], DataTagTable::default());
+        // Tag 95's raw value is the literal length digit "5", distinct from tag 96's data - don't
+        // conflate the two when updating this fixture.
+        assert_eq!(tags.next(), Some(Ok(RawTag { tag: 95, value: b"5" })));
+        assert_eq!(
+            tags.next(),
+            Some(Ok(RawTag {
+                tag: 96,
+                value: b"a\x01b\x01c"
+            }))
+        );
+        assert_eq!(tags.next(), None);
+    }
+
+    #[test]
+    fn data_tags_passes_through_ordinary_tags() {
+        let message = b"8=FIX.4.4\x0135=0\x01";
+        let mut tags = DataTags::new(&message[..], DataTagTable::default());
+        assert_eq!(
+            tags.next(),
+            Some(Ok(RawTag {
+                tag: 8,
+                value: b"FIX.4.4"
+            }))
+        );
+        assert_eq!(tags.next(), Some(Ok(RawTag { tag: 35, value: b"0" })));
+        assert_eq!(tags.next(), None);
+    }
+
+    #[test]
+    fn data_tags_missing_length() {
+        let message = b"96=a\x01b\x01c\x01";
+        let mut tags = DataTags::new(&message[..], DataTagTable::default());
+        assert_eq!(tags.next(), Some(Err(DataTagError::MissingLength)));
+    }
+
+    #[test]
+    fn data_tags_custom_pair() {
+        let mut table = DataTagTable::empty();
+        table.register(90, 91);
+
+        let message = b"90=3\x0191=x\x01y\x01";
+        let mut tags = DataTags::new(&message[..], table);
+        assert_eq!(tags.next(), Some(Ok(RawTag { tag: 90, value: b"3" })));
+        assert_eq!(
+            tags.next(),
+            Some(Ok(RawTag {
+                tag: 91,
+                value: b"x\x01y"
+            }))
+        );
+        assert_eq!(tags.next(), None);
+    }
+
+    #[test]
+    fn parse_tagged_simple() {
+        let message = b"8=FIX.4.4\x019=5\x01";
+        let mut tags = Tags::new(&message[..]);
+        tags.next(); // tag 8
+        let length_tag = tags.next().unwrap();
+
+        let value: u32 = parse_tagged(message, &length_tag).unwrap();
+        assert_eq!(value, 5);
+    }
+
+    #[test]
+    fn parse_tagged_reports_location() {
+        let message = b"8=FIX.4.4\x019=abc\x01";
+        let mut tags = Tags::new(&message[..]);
+        tags.next(); // tag 8
+        let length_tag = tags.next().unwrap();
+
+        let result: Result<u32, _> = parse_tagged(message, &length_tag);
+        let err = result.unwrap_err();
+        assert_eq!(err.tag, 9);
+        assert_eq!(err.value, b"abc");
+        assert_eq!(err.offset, 12);
+        assert_eq!(
+            err.cause,
+            ValueError {
+                kind: crate::data_types::ParseErrorKind::UnsignedInteger,
+                nom_kind: ErrorKind::Digit,
+            }
+        );
+    }
+
     #[test]
     fn itos_simple() {
         let value = 8;